@@ -0,0 +1,79 @@
+//! Compile time assertions on type level values. A failing assertion is a compile error.
+//! ```
+//! use typers::{
+//!     assert_type_eq,
+//!     list::{Cat, End, Len},
+//!     num::unsigned::{Add, Lt, U1, U2, U3, U8},
+//!     static_assert,
+//! };
+//! type List = Cat<i32, Cat<u8, End>>;
+//! static_assert!(Lt<Len<List>, U8>);
+//! assert_type_eq!(Add<U1, U2>, U3);
+//! ```
+//!
+//! The macros expand to items, so they can't refer to the generic parameters of the surrounding
+//! function or impl. Generic code states the same condition as a `where` bound instead, which the
+//! caller then has to satisfy:
+//! ```
+//! use typers::{
+//!     bool::IsTrue,
+//!     list::{Cat, End, Len, TList},
+//!     num::unsigned::{Lt, Unsigned, U8},
+//! };
+//! fn short_len<L: TList>() -> usize
+//! where
+//!     Lt<Len<L>, U8>: IsTrue,
+//! {
+//!     Len::<L>::USIZE
+//! }
+//! assert_eq!(short_len::<Cat<i32, Cat<u8, End>>>(), 2);
+//! ```
+use crate::seal;
+
+/// A trait only implemented by `T` itself. It is used as a bound to require two types to be the
+/// same at compile time. It is sealed so that it can't be implemented for other types.
+/// ```compile_fail
+/// struct Foo;
+/// struct Bar;
+/// impl typers::assert::SameAs<Foo> for Bar {}
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not the same type as `{T}`",
+    note = "if one of them is an `Invalid<Tag>` type, an operation failed and `Tag` tells why"
+)]
+pub trait SameAs<T: ?Sized>: seal::Same<T> {}
+impl<T: ?Sized> SameAs<T> for T {}
+
+/// Fails to compile if the type level boolean is not [struct@crate::bool::True]. It can be used
+/// wherever an item is allowed.
+/// ```compile_fail
+/// use typers::{num::unsigned::{Lt, U3, U2}, static_assert};
+/// static_assert!(Lt<U3, U2>);
+/// ```
+#[macro_export]
+macro_rules! static_assert {
+    ($cond:ty $(,)?) => {
+        const _: fn() = {
+            fn static_assert<B: $crate::bool::IsTrue>() {}
+            static_assert::<$cond>
+        };
+    };
+}
+
+/// Fails to compile if the two types are not the same. It can be used wherever an item is allowed.
+///
+/// Type level numbers are compared by representation: to compare their values use
+/// `static_assert!(Eq<A, B>)`.
+/// ```compile_fail
+/// use typers::{assert_type_eq, num::unsigned::{U3, U2}};
+/// assert_type_eq!(U3, U2);
+/// ```
+#[macro_export]
+macro_rules! assert_type_eq {
+    ($lhs:ty, $rhs:ty $(,)?) => {
+        const _: fn() = {
+            fn assert_type_eq<A: ?Sized + $crate::assert::SameAs<B>, B: ?Sized>() {}
+            assert_type_eq::<$lhs, $rhs>
+        };
+    };
+}
@@ -1,11 +1,17 @@
 #![doc = include_str!("./bool.md")]
 use crate::{
+    cmp::Ordering,
+    invalid::ErrorTag,
     list::TList,
-    num::{bit::Bit, unsigned::Unsigned},
+    num::{bit::Bit, signed::Signed, unsigned::Unsigned},
     seal, Invalid,
 };
 
 /// A trait implemented for all boolean types values.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a type level boolean",
+    note = "type level booleans are `True` and `False`"
+)]
 pub trait Bool: seal::Sealed {
     /// Value equivalent to the type.
     const BOOL: bool;
@@ -27,7 +33,11 @@ pub trait Bool: seal::Sealed {
     #[doc(hidden)]
     type Ifuint<A: Unsigned, B: Unsigned>: Unsigned;
     #[doc(hidden)]
+    type Ifint<A: Signed, B: Signed>: Signed;
+    #[doc(hidden)]
     type Iflist<A: TList, B: TList>: TList;
+    #[doc(hidden)]
+    type Iford<A: Ordering, B: Ordering>: Ordering;
 
     /// Branches need to be closures because they need to be lazily evaluated.
     fn cond<A, B>(a: impl FnOnce() -> A, b: impl FnOnce() -> B) -> Self::If<A, B>;
@@ -44,23 +54,25 @@ pub type Xor<A, B> = <A as Bool>::Xor<B>;
 /// If `Self` is [True] construct type `A` otherwise `B`.
 pub type If<C, A, B> = <C as Bool>::If<A, B>;
 
-impl Bool for Invalid {
+impl<Tag: ErrorTag> Bool for Invalid<Tag> {
     #[doc(hidden)]
-    const BOOL: bool = { panic!("Invalid Bool type value!") };
+    const BOOL: bool = { panic!("{}", Tag::REASON) };
 
-    type Not = Invalid;
-    type And<B: Bool> = Invalid;
-    type Or<B: Bool> = Invalid;
-    type Xor<B: Bool> = Invalid;
+    type Not = Self;
+    type And<B: Bool> = Self;
+    type Or<B: Bool> = Self;
+    type Xor<B: Bool> = Self;
 
-    type If<A, B> = Invalid;
-    type Ifbool<A: Bool, B: Bool> = Invalid;
-    type Ifbit<A: Bit, B: Bit> = Invalid;
-    type Ifuint<A: Unsigned, B: Unsigned> = Invalid;
-    type Iflist<A: TList, B: TList> = Invalid;
+    type If<A, B> = Self;
+    type Ifbool<A: Bool, B: Bool> = Self;
+    type Ifbit<A: Bit, B: Bit> = Self;
+    type Ifuint<A: Unsigned, B: Unsigned> = Self;
+    type Ifint<A: Signed, B: Signed> = Self;
+    type Iflist<A: TList, B: TList> = Self;
+    type Iford<A: Ordering, B: Ordering> = Self;
 
     fn cond<A, B>(_: impl FnOnce() -> A, _: impl FnOnce() -> B) -> Self::If<A, B> {
-        panic!("Attempted to resolve an Invalid condition!")
+        panic!("Attempted to resolve an Invalid condition: {}", Tag::REASON)
     }
 }
 
@@ -78,13 +90,25 @@ impl Bool for True {
     type Ifbool<A: Bool, B: Bool> = A;
     type Ifbit<A: Bit, B: Bit> = A;
     type Ifuint<A: Unsigned, B: Unsigned> = A;
+    type Ifint<A: Signed, B: Signed> = A;
     type Iflist<A: TList, B: TList> = A;
+    type Iford<A: Ordering, B: Ordering> = A;
 
     fn cond<A, B>(a: impl FnOnce() -> A, _: impl FnOnce() -> B) -> Self::If<A, B> {
         a()
     }
 }
 
+/// A trait only implemented by [True]. It is used as a bound to require a condition to hold at
+/// compile time.
+#[diagnostic::on_unimplemented(
+    message = "the type level condition is `{Self}` instead of `True`",
+    label = "this condition does not hold",
+    note = "if the condition is an `Invalid<Tag>` type, an operation failed and `Tag` tells why"
+)]
+pub trait IsTrue: Bool {}
+impl IsTrue for True {}
+
 /// The boolean value `false` in type land.
 pub struct False;
 impl seal::Sealed for False {}
@@ -98,8 +122,10 @@ impl Bool for False {
     type If<A, B> = B;
     type Ifbit<A: Bit, B: Bit> = B;
     type Ifuint<A: Unsigned, B: Unsigned> = B;
+    type Ifint<A: Signed, B: Signed> = B;
     type Ifbool<A: Bool, B: Bool> = B;
     type Iflist<A: TList, B: TList> = B;
+    type Iford<A: Ordering, B: Ordering> = B;
 
     fn cond<A, B>(_: impl FnOnce() -> A, b: impl FnOnce() -> B) -> Self::If<A, B> {
         b()
@@ -107,6 +133,7 @@ impl Bool for False {
 }
 
 #[cfg(test)]
+#[allow(clippy::assertions_on_constants)]
 mod test {
     use super::*;
 
@@ -0,0 +1,104 @@
+#![doc = include_str!("./cmp.md")]
+use crate::{
+    bool::{Bool, False, True},
+    invalid::ErrorTag,
+    seal, Invalid,
+};
+
+/// A trait implemented for the three possible results of a comparison.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a type level ordering",
+    note = "type level orderings are `Less`, `Equal` and `Greater`"
+)]
+pub trait Ordering: seal::Sealed {
+    /// Value equivalent to the type.
+    const ORDERING: core::cmp::Ordering;
+    #[doc(hidden)]
+    type IsLess: Bool;
+    #[doc(hidden)]
+    type IsEqual: Bool;
+    #[doc(hidden)]
+    type IsGreater: Bool;
+    #[doc(hidden)]
+    type Reverse: Ordering;
+    #[doc(hidden)]
+    type Then<O: Ordering>: Ordering;
+}
+
+/// [struct@crate::bool::True] if the ordering is [struct@Less].
+pub type IsLess<O> = <O as Ordering>::IsLess;
+/// [struct@crate::bool::True] if the ordering is [struct@Equal].
+pub type IsEqual<O> = <O as Ordering>::IsEqual;
+/// [struct@crate::bool::True] if the ordering is [struct@Greater].
+pub type IsGreater<O> = <O as Ordering>::IsGreater;
+/// Swaps [struct@Less] and [struct@Greater].
+pub type Reverse<O> = <O as Ordering>::Reverse;
+/// `O` if `Self` is [struct@Equal] otherwise `Self`.
+pub type Then<Lhs, O> = <Lhs as Ordering>::Then<O>;
+
+impl<Tag: ErrorTag> Ordering for Invalid<Tag> {
+    #[doc(hidden)]
+    const ORDERING: core::cmp::Ordering = { panic!("{}", Tag::REASON) };
+    type IsLess = Self;
+    type IsEqual = Self;
+    type IsGreater = Self;
+    type Reverse = Self;
+    type Then<O: Ordering> = Self;
+}
+
+/// The left hand side is less than the right hand side.
+pub struct Less;
+impl seal::Sealed for Less {}
+impl Ordering for Less {
+    const ORDERING: core::cmp::Ordering = core::cmp::Ordering::Less;
+    type IsLess = True;
+    type IsEqual = False;
+    type IsGreater = False;
+    type Reverse = Greater;
+    type Then<O: Ordering> = Self;
+}
+
+/// Both sides are equal.
+pub struct Equal;
+impl seal::Sealed for Equal {}
+impl Ordering for Equal {
+    const ORDERING: core::cmp::Ordering = core::cmp::Ordering::Equal;
+    type IsLess = False;
+    type IsEqual = True;
+    type IsGreater = False;
+    type Reverse = Self;
+    type Then<O: Ordering> = O;
+}
+
+/// The left hand side is greater than the right hand side.
+pub struct Greater;
+impl seal::Sealed for Greater {}
+impl Ordering for Greater {
+    const ORDERING: core::cmp::Ordering = core::cmp::Ordering::Greater;
+    type IsLess = False;
+    type IsEqual = False;
+    type IsGreater = True;
+    type Reverse = Less;
+    type Then<O: Ordering> = Self;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_then() {
+        assert_eq!(
+            <Then<Equal, Less> as Ordering>::ORDERING,
+            core::cmp::Ordering::Less
+        );
+        assert_eq!(
+            <Then<Greater, Less> as Ordering>::ORDERING,
+            core::cmp::Ordering::Greater
+        );
+        assert_eq!(
+            <Reverse<Less> as Ordering>::ORDERING,
+            core::cmp::Ordering::Greater
+        );
+    }
+}
@@ -0,0 +1,230 @@
+#![doc = include_str!("./describe.md")]
+use std::fmt;
+
+use crate::{
+    bool::{Bool, False, True},
+    cmp::{Equal, Greater, Less, Ordering},
+    fallible,
+    invalid::ErrorTag,
+    list::{Cat, End, TList},
+    num::{
+        bit::{Bit, B0, B1},
+        signed::{NInt, PInt, Signed, Z0},
+        unsigned::{Last, UInt, Unsigned},
+    },
+    seal, Invalid,
+};
+
+/// A trait implemented by the type level values to describe them as a string.
+pub trait Describe {
+    /// The description of `Self`.
+    fn describe() -> String;
+}
+
+impl<Tag: ErrorTag> Describe for Invalid<Tag> {
+    fn describe() -> String {
+        format!("Invalid<{}>", Tag::NAME)
+    }
+}
+
+impl Describe for True {
+    fn describe() -> String {
+        Self::BOOL.to_string()
+    }
+}
+
+impl Describe for False {
+    fn describe() -> String {
+        Self::BOOL.to_string()
+    }
+}
+
+impl Describe for B0 {
+    fn describe() -> String {
+        Self::USIZE.to_string()
+    }
+}
+
+impl Describe for B1 {
+    fn describe() -> String {
+        Self::USIZE.to_string()
+    }
+}
+
+impl<B: Bit> Describe for Last<B> {
+    fn describe() -> String {
+        Self::USIZE.to_string()
+    }
+}
+
+impl<Msbs: Unsigned, Lsb: Bit> Describe for UInt<Msbs, Lsb> {
+    fn describe() -> String {
+        Self::USIZE.to_string()
+    }
+}
+
+impl Describe for Z0 {
+    fn describe() -> String {
+        Self::ISIZE.to_string()
+    }
+}
+
+impl<U: Unsigned> Describe for PInt<U> {
+    fn describe() -> String {
+        Self::ISIZE.to_string()
+    }
+}
+
+impl<U: Unsigned> Describe for NInt<U> {
+    fn describe() -> String {
+        Self::ISIZE.to_string()
+    }
+}
+
+impl Describe for Less {
+    fn describe() -> String {
+        format!("{:?}", Self::ORDERING)
+    }
+}
+
+impl Describe for Equal {
+    fn describe() -> String {
+        format!("{:?}", Self::ORDERING)
+    }
+}
+
+impl Describe for Greater {
+    fn describe() -> String {
+        format!("{:?}", Self::ORDERING)
+    }
+}
+
+/// A helper trait used to describe the elements of a [trait@TList].
+#[doc(hidden)]
+pub trait DescribeElems: TList + seal::Sealed {
+    fn describe_elems(elems: &mut Vec<String>);
+}
+
+impl DescribeElems for End {
+    fn describe_elems(_elems: &mut Vec<String>) {}
+}
+
+impl<H: Describe, T: DescribeElems> DescribeElems for Cat<H, T> {
+    fn describe_elems(elems: &mut Vec<String>) {
+        elems.push(H::describe());
+        T::describe_elems(elems);
+    }
+}
+
+impl Describe for End {
+    fn describe() -> String {
+        "[]".into()
+    }
+}
+
+impl<H: Describe, T: DescribeElems> Describe for Cat<H, T> {
+    fn describe() -> String {
+        let mut elems = Vec::with_capacity(Self::LEN);
+        Self::describe_elems(&mut elems);
+        format!("[{}]", elems.join(", "))
+    }
+}
+
+impl<T: Describe> Describe for fallible::Some<T> {
+    fn describe() -> String {
+        format!("Some({})", T::describe())
+    }
+}
+
+impl Describe for fallible::None {
+    fn describe() -> String {
+        "None".into()
+    }
+}
+
+impl<T: Describe> Describe for fallible::Ok<T> {
+    fn describe() -> String {
+        format!("Ok({})", T::describe())
+    }
+}
+
+impl<E: Describe> Describe for fallible::Err<E> {
+    fn describe() -> String {
+        format!("Err({})", E::describe())
+    }
+}
+
+// The type level values are zero sized so they can also be displayed.
+macro_rules! impl_display {
+    ($([$($generics:tt)*] $ty:ty),* $(,)?) => {
+        $(
+            impl<$($generics)*> fmt::Display for $ty {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.write_str(&<Self as Describe>::describe())
+                }
+            }
+        )*
+    };
+}
+
+impl_display!(
+    [] True,
+    [] False,
+    [] B0,
+    [] B1,
+    [B: Bit] Last<B>,
+    [Msbs: Unsigned, Lsb: Bit] UInt<Msbs, Lsb>,
+    [] Z0,
+    [U: Unsigned] PInt<U>,
+    [U: Unsigned] NInt<U>,
+    [T: Describe] fallible::Some<T>,
+    [] fallible::None,
+    [T: Describe] fallible::Ok<T>,
+    [E: Describe] fallible::Err<E>,
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        num::{
+            signed::{N2, P7},
+            unsigned::{self, U0, U1, U12, U3, U4},
+        },
+        Tlist, U,
+    };
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(<unsigned::Add<U3, U4>>::describe(), "7");
+        assert_eq!(U0::describe(), "0");
+        assert_eq!(<U![1_000_000]>::describe(), "1000000");
+        assert_eq!(True::describe(), "true");
+        assert_eq!(<crate::bool::Not<True>>::describe(), "false");
+        assert_eq!(N2::describe(), "-2");
+        assert_eq!(<unsigned::Cmp<U1, U3>>::describe(), "Less");
+        assert_eq!(<Invalid>::describe(), "Invalid<Unknown>");
+        assert_eq!(<unsigned::Div<U1, U0>>::describe(), "Invalid<DivByZero>");
+        assert_eq!(<unsigned::CheckedSub<U4, U1>>::describe(), "Some(3)");
+        assert_eq!(<unsigned::CheckedSub<U1, U4>>::describe(), "None");
+        assert_eq!(<fallible::Err<Less>>::describe(), "Err(Less)");
+    }
+
+    #[test]
+    fn test_describe_list() {
+        assert_eq!(End::describe(), "[]");
+        assert_eq!(<Tlist![U1]>::describe(), "[1]");
+        assert_eq!(<Tlist![U1, U4, U12]>::describe(), "[1, 4, 12]");
+        assert_eq!(
+            <Tlist![U1, True, P7, Tlist![U3, False], End]>::describe(),
+            "[1, true, 7, [3, false], []]"
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(U12::default().to_string(), "12");
+        assert_eq!(format!("{}", True), "true");
+        assert_eq!(format!("{}", N2::default()), "-2");
+    }
+}
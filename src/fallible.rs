@@ -0,0 +1,218 @@
+#![doc = include_str!("./fallible.md")]
+use crate::{
+    bool::{self, Bool, False, True},
+    func::{Apply, TypeFn},
+    invalid::{ErrorTag, NoValue},
+    seal, Invalid,
+};
+
+/// A trait implemented by the results of fallible type level operations.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a fallible type",
+    note = "fallible types are `Some`, `None`, `Ok` and `Err`"
+)]
+pub trait Fallible: seal::Sealed {
+    #[doc(hidden)]
+    type Unwrap;
+    #[doc(hidden)]
+    type UnwrapOr<D>;
+    #[doc(hidden)]
+    type Map<F: TypeFn>: Fallible;
+}
+
+/// The type level Option: [struct@Some] or [struct@None].
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a type level option",
+    note = "type level options are `Some` and `None`"
+)]
+pub trait TOption: Fallible {
+    #[doc(hidden)]
+    type IsSome: Bool;
+}
+
+/// The type level Result: [struct@Ok] or [struct@Err].
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a type level result",
+    note = "type level results are `Ok` and `Err`"
+)]
+pub trait TResult: Fallible {
+    #[doc(hidden)]
+    type IsOk: Bool;
+    #[doc(hidden)]
+    type UnwrapErr;
+}
+
+/// A trait implemented by the [trait@Fallible] types that can be chained with the [trait@TypeFn]
+/// `F`. `F` has to return a [trait@TOption] for an option and a [trait@TResult] for a result.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be chained with `{F}`",
+    note = "the function has to return a `TOption` for `Some` and a `TResult` for `Ok`"
+)]
+pub trait FallibleAndThen<F>: Fallible {
+    #[doc(hidden)]
+    type AndThen: Fallible;
+}
+
+/// [struct@True] if `O` is [struct@Some].
+pub type IsSome<O> = <O as TOption>::IsSome;
+/// [struct@True] if `O` is [struct@None].
+pub type IsNone<O> = bool::Not<IsSome<O>>;
+/// [struct@True] if `R` is [struct@Ok].
+pub type IsOk<R> = <R as TResult>::IsOk;
+/// [struct@True] if `R` is [struct@Err].
+pub type IsErr<R> = bool::Not<IsOk<R>>;
+/// The wrapped type of `F`. [Invalid] if there is none.
+pub type Unwrap<F> = <F as Fallible>::Unwrap;
+/// The wrapped type of `F` or `D` if there is none.
+pub type UnwrapOr<F, D> = <F as Fallible>::UnwrapOr<D>;
+/// The error type of the [struct@Err] `R`. [Invalid] for [struct@Ok].
+pub type UnwrapErr<R> = <R as TResult>::UnwrapErr;
+/// `F` with the [trait@TypeFn] `Fn` applied to its wrapped type.
+pub type Map<F, Fn> = <F as Fallible>::Map<Fn>;
+/// The result of applying the [trait@TypeFn] `Fn` to the wrapped type of `F`. The failures are
+/// kept as is.
+pub type AndThen<F, Fn> = <F as FallibleAndThen<Fn>>::AndThen;
+
+impl<Tag: ErrorTag> Fallible for Invalid<Tag> {
+    type Unwrap = Self;
+    type UnwrapOr<D> = Self;
+    type Map<F: TypeFn> = Self;
+}
+impl<Tag: ErrorTag> TOption for Invalid<Tag> {
+    type IsSome = Self;
+}
+impl<Tag: ErrorTag> TResult for Invalid<Tag> {
+    type IsOk = Self;
+    type UnwrapErr = Self;
+}
+impl<Tag: ErrorTag, F> FallibleAndThen<F> for Invalid<Tag> {
+    type AndThen = Self;
+}
+
+/// The value of type `T` is present.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Some<T>(pub T);
+impl<T> seal::Sealed for Some<T> {}
+impl<T> Fallible for Some<T> {
+    type Unwrap = T;
+    type UnwrapOr<D> = T;
+    type Map<F: TypeFn> = Some<Apply<F, T>>;
+}
+impl<T> TOption for Some<T> {
+    type IsSome = True;
+}
+impl<T, F: TypeFn> FallibleAndThen<F> for Some<T>
+where
+    Apply<F, T>: TOption,
+{
+    type AndThen = Apply<F, T>;
+}
+
+/// There is no value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct None;
+impl seal::Sealed for None {}
+impl Fallible for None {
+    type Unwrap = Invalid<NoValue>;
+    type UnwrapOr<D> = D;
+    type Map<F: TypeFn> = None;
+}
+impl TOption for None {
+    type IsSome = False;
+}
+impl<F: TypeFn> FallibleAndThen<F> for None {
+    type AndThen = None;
+}
+
+/// The operation succeeded with the value of type `T`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Ok<T>(pub T);
+impl<T> seal::Sealed for Ok<T> {}
+impl<T> Fallible for Ok<T> {
+    type Unwrap = T;
+    type UnwrapOr<D> = T;
+    type Map<F: TypeFn> = Ok<Apply<F, T>>;
+}
+impl<T> TResult for Ok<T> {
+    type IsOk = True;
+    type UnwrapErr = Invalid<NoValue>;
+}
+impl<T, F: TypeFn> FallibleAndThen<F> for Ok<T>
+where
+    Apply<F, T>: TResult,
+{
+    type AndThen = Apply<F, T>;
+}
+
+/// The operation failed with the error of type `E`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Err<E>(pub E);
+impl<E> seal::Sealed for Err<E> {}
+impl<E> Fallible for Err<E> {
+    type Unwrap = Invalid<NoValue>;
+    type UnwrapOr<D> = D;
+    type Map<F: TypeFn> = Self;
+}
+impl<E> TResult for Err<E> {
+    type IsOk = False;
+    type UnwrapErr = E;
+}
+impl<E, F: TypeFn> FallibleAndThen<F> for Err<E> {
+    type AndThen = Self;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        assert_type_eq,
+        num::unsigned::{U1, U2},
+        static_assert,
+    };
+
+    struct Boxed;
+    impl TypeFn for Boxed {
+        type Output<T> = Box<T>;
+    }
+
+    // Fallible functions that always succeed.
+    struct SomeBoxed;
+    impl TypeFn for SomeBoxed {
+        type Output<T> = Some<Box<T>>;
+    }
+
+    struct BoxedOrErr;
+    impl TypeFn for BoxedOrErr {
+        type Output<T> = Ok<Box<T>>;
+    }
+
+    #[test]
+    fn test_option() {
+        static_assert!(IsSome<Some<U1>>);
+        static_assert!(IsNone<None>);
+        assert_type_eq!(Unwrap<Some<U1>>, U1);
+        assert_type_eq!(Unwrap<None>, Invalid<NoValue>);
+        assert_type_eq!(UnwrapOr<Some<U1>, U2>, U1);
+        assert_type_eq!(UnwrapOr<None, U2>, U2);
+        assert_type_eq!(Map<Some<U1>, Boxed>, Some<Box<U1>>);
+        assert_type_eq!(Map<None, Boxed>, None);
+        assert_type_eq!(AndThen<Some<U1>, SomeBoxed>, Some<Box<U1>>);
+        assert_type_eq!(AndThen<None, SomeBoxed>, None);
+    }
+
+    #[test]
+    fn test_result() {
+        static_assert!(IsOk<Ok<U1>>);
+        static_assert!(IsErr<Err<&str>>);
+        assert_type_eq!(Unwrap<Ok<U1>>, U1);
+        assert_type_eq!(Unwrap<Err<U2>>, Invalid<NoValue>);
+        assert_type_eq!(UnwrapOr<Ok<U1>, U2>, U1);
+        assert_type_eq!(UnwrapOr<Err<()>, U2>, U2);
+        assert_type_eq!(UnwrapErr<Err<U2>>, U2);
+        assert_type_eq!(UnwrapErr<Ok<U1>>, Invalid<NoValue>);
+        assert_type_eq!(Map<Ok<U1>, Boxed>, Ok<Box<U1>>);
+        assert_type_eq!(Map<Err<U2>, Boxed>, Err<U2>);
+        assert_type_eq!(AndThen<Ok<U1>, BoxedOrErr>, Ok<Box<U1>>);
+        assert_type_eq!(AndThen<Err<U2>, BoxedOrErr>, Err<U2>);
+    }
+}
@@ -0,0 +1,42 @@
+#![doc = include_str!("./func.md")]
+
+/// A type level function taking one type argument.
+/// ```ignore
+/// struct Boxed;
+/// impl TypeFn for Boxed {
+///     type Output<T> = Box<T>;
+/// }
+/// ```
+pub trait TypeFn {
+    /// The result of the function applied to `T`.
+    type Output<T>;
+}
+
+/// The result of applying `F` to `T`.
+pub type Apply<F, T> = <F as TypeFn>::Output<T>;
+
+/// The value level counterpart of a [trait@TypeFn] for the argument type `T`.
+pub trait Mapper<T>: TypeFn {
+    fn map(&mut self, elem: T) -> Apply<Self, T>;
+}
+
+/// A type level function taking two type arguments. Unlike [trait@TypeFn] it is implemented for
+/// each pair of accepted arguments which allows using their traits.
+/// ```ignore
+/// struct Sum;
+/// impl<Acc: Unsigned, T: Unsigned> TypeFn2<Acc, T> for Sum {
+///     type Output = unsigned::Add<Acc, T>;
+/// }
+/// ```
+pub trait TypeFn2<A, B> {
+    /// The result of the function applied to `A` and `B`.
+    type Output;
+}
+
+/// The result of applying `F` to `A` and `B`.
+pub type Apply2<F, A, B> = <F as TypeFn2<A, B>>::Output;
+
+/// The value level counterpart of a [trait@TypeFn2] used to fold a [trait@crate::list::TList].
+pub trait Folder<Acc, T>: TypeFn2<Acc, T> {
+    fn fold(&mut self, acc: Acc, elem: T) -> Apply2<Self, Acc, T>;
+}
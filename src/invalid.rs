@@ -0,0 +1,77 @@
+//! The reasons of the failures of type level operations. An operation that can't be performed
+//! results in [struct@Invalid] tagged with an [trait@ErrorTag] telling why. The operations on an
+//! [struct@Invalid] type keep its tag so the first failure is the one reported.
+//! ```
+//! use typers::{
+//!     assert_type_eq,
+//!     invalid::{DivByZero, Invalid},
+//!     num::unsigned::{Add, Div, U0, U1},
+//! };
+//! assert_type_eq!(Add<Div<U1, U0>, U1>, Invalid<DivByZero>);
+//! ```
+//! Using the value of an [struct@Invalid] type fails to compile with the reason as message:
+//! ```compile_fail
+//! use typers::num::unsigned::{Div, Unsigned, U0, U1};
+//! // error: evaluation panicked: division by zero
+//! let _ = <Div<U1, U0> as Unsigned>::USIZE;
+//! ```
+use std::marker;
+
+use crate::seal;
+
+/// Invalid represent the result of any operation that can't be performed on a type (e.g.
+/// Decrementing the Representation of 0 in usigned numbers). `Tag` is the reason of the failure.
+pub struct Invalid<Tag: ErrorTag = Unknown>(marker::PhantomData<Tag>);
+impl<Tag: ErrorTag> seal::Sealed for Invalid<Tag> {}
+
+impl<Tag: ErrorTag> Default for Invalid<Tag> {
+    fn default() -> Self {
+        Invalid(marker::PhantomData)
+    }
+}
+
+/// A trait implemented by the reasons of failure of the type level operations.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not an error tag",
+    note = "error tags are types implementing `ErrorTag`, such as `DivByZero` or `Underflow`"
+)]
+pub trait ErrorTag {
+    /// The name of the tag.
+    const NAME: &'static str;
+    /// The description of the failure.
+    const REASON: &'static str;
+}
+
+macro_rules! error_tags {
+    ($($(#[$attr:meta])* $tag:ident => $reason:literal),* $(,)?) => {
+        $(
+            $(#[$attr])*
+            pub struct $tag;
+            impl ErrorTag for $tag {
+                const NAME: &'static str = stringify!($tag);
+                const REASON: &'static str = $reason;
+            }
+        )*
+    };
+}
+
+error_tags!(
+    /// The failure has no specific reason.
+    Unknown => "invalid type level value",
+    /// A division or a remainder by zero.
+    DivByZero => "division by zero",
+    /// A subtraction or a decrement going below zero.
+    Underflow => "unsigned underflow",
+    /// An index past the end of a list.
+    IndexOutOfBounds => "index out of bounds",
+    /// Two lists that should have the same length don't.
+    LengthMismatch => "lists of different lengths",
+    /// The logarithm of zero.
+    LogOfZero => "logarithm of zero",
+    /// An interval with its lower bound greater than its upper bound.
+    InvalidBounds => "lower bound greater than the upper bound",
+    /// A negative number converted to an unsigned one.
+    Negative => "negative number",
+    /// Unwrapping a type without value such as `None` or `Err`.
+    NoValue => "unwrapped a type without value",
+);
@@ -1,15 +1,40 @@
 #![doc = include_str!("../README.md")]
+pub mod assert;
 pub mod bool;
+pub mod cmp;
+pub mod describe;
+pub mod fallible;
+pub mod func;
+pub mod invalid;
 pub mod list;
 pub mod num;
+#[cfg(feature = "serde")]
+mod serde;
+
+// Allows the procedural macros to refer to `::typers` from inside the crate.
+extern crate self as typers;
+
+/// A macro to define [trait@num::unsigned::Unsigned] types from a decimal, hexadecimal, octal or
+/// binary integer literal.
+/// ```
+/// use typers::{num::unsigned::Unsigned, U};
+/// assert_eq!(<U![4096] as Unsigned>::USIZE, 4096);
+/// assert_eq!(<U![0xFF] as Unsigned>::USIZE, 255);
+/// ```
+/// Literals that don't fit in a `u128` are rejected.
+/// ```compile_fail
+/// type TooBig = typers::U![340282366920938463463374607431768211456];
+/// ```
+pub use typers_macros::U;
+
+pub use invalid::Invalid;
 
 mod seal {
     pub trait Sealed {}
     // A struct to disallow the calling of a function.
     pub struct Key {}
     impl Sealed for Key {}
+    // Only implemented by `T` itself so that `SameAs` can't be implemented outside of the crate.
+    pub trait Same<T: ?Sized> {}
+    impl<T: ?Sized> Same<T> for T {}
 }
-/// Invalid represent the result of any operation that can't be performed on a type.(e.g.
-/// Decrementing the Representation of 0 in usigned numbers)
-pub struct Invalid;
-impl seal::Sealed for Invalid {}
@@ -3,20 +3,36 @@ use std::marker;
 
 use crate::{
     bool::{Bool, False, If, True},
-    num::unsigned::{self, IsZero, Unsigned, U0},
+    fallible,
+    func::{Apply, Apply2, Folder, Mapper, TypeFn, TypeFn2},
+    invalid::{ErrorTag, IndexOutOfBounds, LengthMismatch},
+    num::{
+        bit::{Bit, B0, B1},
+        unsigned::{self, IsZero, Last, UInt, Unsigned, U0},
+    },
     seal, Invalid,
 };
 
 /// A macro to define simple TList types.
 /// ```ignore
-/// type Numbers = TList![i32, i16, i8];
+/// type Numbers = Tlist![i32, i16, i8];
 /// // Expands to:
-/// type Numbers = Cons<i32, Cons<i16, Cons<i8, End>>>;
+/// type Numbers = Cat<i32, Cat<i16, Cat<i8, End>>>;
 /// ```
 #[macro_export]
 macro_rules! Tlist {
-    ($ty1:ident, $($ty2:ident),*) => {
-        Cat<$ty1, tlist!($($ty2),*)>
+    ($ty1:ty $(, $ty2:ty)*) => {
+        Cat<$ty1, Tlist!($($ty2),*)>
+    };
+    () => {
+        End
+    }
+}
+
+// A macro to destructure TList values.
+macro_rules! tlist_pat {
+    ($v1:ident $(, $v2:ident)*) => {
+        Cat { head: $v1, tail: tlist_pat!($($v2),*) }
     };
     () => {
         End
@@ -52,6 +68,10 @@ macro_rules! tlist {
 }
 
 /// The Type List trait. Implemented by [struct@Cat] and [struct@End].
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a `TList`",
+    note = "type lists are built from `Cat` and `End`, for example with the `Tlist!` macro"
+)]
 pub trait TList: Sized + seal::Sealed {
     /// Short hand for the usize value of the Len.
     const LEN: usize = <Self::Len as Unsigned>::USIZE;
@@ -66,30 +86,72 @@ pub trait TList: Sized + seal::Sealed {
     type Concat<L: TList>: TList;
     #[doc(hidden)]
     type _Reverse<L: TList>: TList;
+    #[doc(hidden)]
+    type Map<F: TypeFn>: TList;
+    #[doc(hidden)]
+    type Zip<L: TList>: TList;
+    #[doc(hidden)]
+    type _ZipWith<H, T: TList>: TList;
 
     /// The length of the [trait@TList].
     fn len(&self) -> usize {
         Self::LEN
     }
 
+    /// Whether the [trait@TList] is empty.
+    fn is_empty(&self) -> bool {
+        <Self::IsEmpty as Bool>::BOOL
+    }
+
     fn push<E>(self, elem: E) -> Push<Self, E>;
     fn reverse(self) -> Reverse<Self>;
     fn concat<L: TList>(self, other: L) -> Concat<Self, L>;
 
+    /// Split the [trait@TList] after its `N` first elements. Doesn't compile if `N` is greater
+    /// than the length of the list.
+    fn split_at<N: Unsigned>(self) -> SplitAt<Self, N>
+    where
+        Self: TListSplit<N>,
+    {
+        self._split_at(seal::Key {})
+    }
+
+    /// The `N` first elements of the [trait@TList].
+    fn take<N: Unsigned>(self) -> Take<Self, N>
+    where
+        Self: TListSplit<N>,
+    {
+        self._split_at(seal::Key {}).0
+    }
+
+    /// The [trait@TList] without its `N` first elements.
+    fn skip<N: Unsigned>(self) -> Skip<Self, N>
+    where
+        Self: TListSplit<N>,
+    {
+        self._split_at(seal::Key {}).1
+    }
+
     // This method is hidden and sealed as its not intended to be called by the user.
     #[doc(hidden)]
     fn _reverse<T: TList>(self, list: T, _key: seal::Key) -> Self::_Reverse<T>;
 }
-impl TList for Invalid {
-    type IsEmpty = Invalid;
+impl<Tag: ErrorTag> TList for Invalid<Tag> {
+    type IsEmpty = Self;
+
+    type Len = Self;
+
+    type Push<T> = Self;
+
+    type Concat<L: TList> = Self;
 
-    type Len = Invalid;
+    type _Reverse<L: TList> = Self;
 
-    type Push<T> = Invalid;
+    type Map<F: TypeFn> = Self;
 
-    type Concat<L: TList> = Invalid;
+    type Zip<L: TList> = Self;
 
-    type _Reverse<L: TList> = Invalid;
+    type _ZipWith<H, T: TList> = Self;
 
     fn push<E>(self, _elem: E) -> Push<Self, E> {
         unreachable!()
@@ -118,9 +180,17 @@ pub type Push<L, T> = <L as TList>::Push<T>;
 pub type Concat<Lhs, Rhs> = <Lhs as TList>::Concat<Rhs>;
 /// Return the [trait@TList] that is the reverse of `L`.
 pub type Reverse<L> = <L as TList>::_Reverse<End>;
+/// Return the [trait@TList] where the type function `F` is applied to each element of `L`.
+pub type Map<L, F> = <L as TList>::Map<F>;
+/// Return the [trait@TList] of the pairs `(A, B)` of the elements of `Lhs` and `Rhs`. If the
+/// lengths differ it is [Invalid].
+pub type Zip<Lhs, Rhs> = <Lhs as TList>::Zip<Rhs>;
 
 /// A struct representing the head of the list concatenated with the rest (tail) of it.
-#[derive(Debug)]
+///
+/// The standard traits are implemented when all the elements implement them. Lists are compared
+/// lexicographically: the heads are compared first and the tails only if the heads are equal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 pub struct Cat<H, T: TList> {
     pub head: H,
     pub tail: T,
@@ -129,7 +199,7 @@ pub struct Cat<H, T: TList> {
 impl<H, T: TList> seal::Sealed for Cat<H, T> {}
 
 /// A struct not holding any value representing the end of the Type list.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 pub struct End;
 impl seal::Sealed for End {}
 
@@ -140,6 +210,10 @@ impl TList for End {
     type Push<T> = Cat<T, End>;
     type Concat<L: TList> = L;
     type _Reverse<L: TList> = L;
+    type Map<F: TypeFn> = End;
+    type Zip<L: TList> =
+        <L::IsEmpty as Bool>::Iflist</*Then*/ End, /*Else*/ Invalid<LengthMismatch>>;
+    type _ZipWith<H, T: TList> = Invalid<LengthMismatch>;
 
     fn push<E>(self, elem: E) -> Push<Self, E> {
         Cat {
@@ -168,6 +242,10 @@ impl<H, T: TList> TList for Cat<H, T> {
     type Push<E> = Cat<E, Self>;
     type Concat<L: TList> = Cat<H, T::Concat<L>>;
     type _Reverse<L: TList> = T::_Reverse<Cat<H, L>>;
+    type Map<F: TypeFn> = Cat<Apply<F, H>, T::Map<F>>;
+    type Zip<L: TList> = L::_ZipWith<H, T>;
+    // Pushing onto the zipped tails propagates the Invalid if their lengths differ.
+    type _ZipWith<H2, T2: TList> = Push<T2::Zip<T>, (H2, H)>;
 
     fn push<E>(self, elem: E) -> Push<Self, E> {
         Cat {
@@ -198,7 +276,266 @@ impl<H, T: TList> TList for Cat<H, T> {
     }
 }
 
+/// A trait to apply a [trait@Mapper] to each element of a [trait@TList].
+pub trait TListMap<F: TypeFn>: TList {
+    fn map(self, mut mapper: F) -> Map<Self, F> {
+        self._map(&mut mapper, seal::Key {})
+    }
+
+    // This method is hidden and sealed as its not intended to be called by the user.
+    #[doc(hidden)]
+    fn _map(self, mapper: &mut F, _key: seal::Key) -> Map<Self, F>;
+}
+
+impl<F: TypeFn> TListMap<F> for End {
+    fn _map(self, _mapper: &mut F, _key: seal::Key) -> Map<Self, F> {
+        End
+    }
+}
+
+impl<F: Mapper<H>, H, T: TListMap<F>> TListMap<F> for Cat<H, T> {
+    fn _map(self, mapper: &mut F, _key: seal::Key) -> Map<Self, F> {
+        Cat {
+            head: mapper.map(self.head),
+            tail: self.tail._map(mapper, seal::Key {}),
+        }
+    }
+}
+
+/// A trait to fold a [trait@TList] from its head to its end with the type level function `F` and
+/// the initial accumulator `Acc`.
+pub trait TListFoldL<F, Acc>: TList {
+    #[doc(hidden)]
+    type FoldL;
+}
+
+/// A trait to fold a [trait@TList] from its end to its head with the type level function `F` and
+/// the initial accumulator `Acc`. `F` still receives the accumulator as its first argument.
+pub trait TListFoldR<F, Acc>: TList {
+    #[doc(hidden)]
+    type FoldR;
+}
+
+/// The result of folding `L` from the left with `F` starting with `Acc`.
+pub type FoldL<L, F, Acc> = <L as TListFoldL<F, Acc>>::FoldL;
+/// The result of folding `L` from the right with `F` starting with `Acc`.
+pub type FoldR<L, F, Acc> = <L as TListFoldR<F, Acc>>::FoldR;
+
+impl<F, Acc> TListFoldL<F, Acc> for End {
+    type FoldL = Acc;
+}
+
+impl<F: TypeFn2<Acc, H>, Acc, H, T: TListFoldL<F, Apply2<F, Acc, H>>> TListFoldL<F, Acc>
+    for Cat<H, T>
+{
+    type FoldL = FoldL<T, F, Apply2<F, Acc, H>>;
+}
+
+impl<F, Acc> TListFoldR<F, Acc> for End {
+    type FoldR = Acc;
+}
+
+impl<F: TypeFn2<FoldR<T, F, Acc>, H>, Acc, H, T: TListFoldR<F, Acc>> TListFoldR<F, Acc>
+    for Cat<H, T>
+{
+    type FoldR = Apply2<F, FoldR<T, F, Acc>, H>;
+}
+
+/// A trait to fold the values of a [trait@TList] from its head with a [trait@Folder].
+pub trait TListFold<F, Acc>: TListFoldL<F, Acc> {
+    fn fold(self, mut folder: F, init: Acc) -> FoldL<Self, F, Acc> {
+        self._fold(&mut folder, init, seal::Key {})
+    }
+
+    // This method is hidden and sealed as its not intended to be called by the user.
+    #[doc(hidden)]
+    fn _fold(self, folder: &mut F, init: Acc, _key: seal::Key) -> FoldL<Self, F, Acc>;
+}
+
+impl<F, Acc> TListFold<F, Acc> for End {
+    fn _fold(self, _folder: &mut F, init: Acc, _key: seal::Key) -> FoldL<Self, F, Acc> {
+        init
+    }
+}
+
+impl<F: Folder<Acc, H>, Acc, H, T: TListFold<F, Apply2<F, Acc, H>>> TListFold<F, Acc>
+    for Cat<H, T>
+{
+    fn _fold(self, folder: &mut F, init: Acc, _key: seal::Key) -> FoldL<Self, F, Acc> {
+        let acc = folder.fold(init, self.head);
+        self.tail._fold(folder, acc, seal::Key {})
+    }
+}
+
+/// A trait to fold the values of a [trait@TList] from its end with a [trait@Folder].
+pub trait TListRFold<F, Acc>: TListFoldR<F, Acc> {
+    fn rfold(self, mut folder: F, init: Acc) -> FoldR<Self, F, Acc> {
+        self._rfold(&mut folder, init, seal::Key {})
+    }
+
+    // This method is hidden and sealed as its not intended to be called by the user.
+    #[doc(hidden)]
+    fn _rfold(self, folder: &mut F, init: Acc, _key: seal::Key) -> FoldR<Self, F, Acc>;
+}
+
+impl<F, Acc> TListRFold<F, Acc> for End {
+    fn _rfold(self, _folder: &mut F, init: Acc, _key: seal::Key) -> FoldR<Self, F, Acc> {
+        init
+    }
+}
+
+impl<F: Folder<FoldR<T, F, Acc>, H>, Acc, H, T: TListRFold<F, Acc>> TListRFold<F, Acc>
+    for Cat<H, T>
+{
+    fn _rfold(self, folder: &mut F, init: Acc, _key: seal::Key) -> FoldR<Self, F, Acc> {
+        let acc = self.tail._rfold(folder, init, seal::Key {});
+        folder.fold(acc, self.head)
+    }
+}
+
+/// A trait to zip the values of two [trait@TList] of the same length.
+pub trait TListZip<L: TList>: TList {
+    fn zip(self, other: L) -> Zip<Self, L>;
+}
+
+impl TListZip<End> for End {
+    fn zip(self, _other: End) -> Zip<Self, End> {
+        End
+    }
+}
+
+impl<H, T: TListZip<T2>, H2, T2: TList> TListZip<Cat<H2, T2>> for Cat<H, T> {
+    fn zip(self, other: Cat<H2, T2>) -> Zip<Self, Cat<H2, T2>> {
+        self.tail.zip(other.tail).push((self.head, other.head))
+    }
+}
+
+/// A trait implemented by the [trait@TList] of pairs to split them in two lists.
+pub trait TListUnzip: TList {
+    #[doc(hidden)]
+    type Left: TList;
+    #[doc(hidden)]
+    type Right: TList;
+
+    fn unzip(self) -> Unzip<Self>;
+}
+
+/// The [trait@TList] of the first elements of the pairs of `L`.
+pub type UnzipLeft<L> = <L as TListUnzip>::Left;
+/// The [trait@TList] of the second elements of the pairs of `L`.
+pub type UnzipRight<L> = <L as TListUnzip>::Right;
+/// The two [trait@TList] resulting of unzipping `L`.
+pub type Unzip<L> = (UnzipLeft<L>, UnzipRight<L>);
+
+impl TListUnzip for End {
+    type Left = End;
+    type Right = End;
+
+    fn unzip(self) -> Unzip<Self> {
+        (End, End)
+    }
+}
+
+impl<A, B, T: TListUnzip> TListUnzip for Cat<(A, B), T> {
+    type Left = Cat<A, T::Left>;
+    type Right = Cat<B, T::Right>;
+
+    fn unzip(self) -> Unzip<Self> {
+        let (a, b) = self.head;
+        let (left, right) = self.tail.unzip();
+        (
+            Cat {
+                head: a,
+                tail: left,
+            },
+            Cat {
+                head: b,
+                tail: right,
+            },
+        )
+    }
+}
+
+/// A trait implemented by the [trait@TList] that have at least `N` elements to split them after
+/// their `N` first elements. `N` has to be in its canonical form (no leading zero bits).
+/// ```compile_fail
+/// use typers::{list::{Cat, End, TList}, num::unsigned::U3, tlist};
+/// let list = tlist![0i32, "Foo"];
+/// list.take::<U3>();
+/// ```
+// A failure is reported on the innermost recursion step so the message doesn't quote its types.
+#[diagnostic::on_unimplemented(
+    message = "a `TList` can't be split after more elements than it has",
+    label = "the list is too short",
+    note = "splitting a list after `N` elements requires it to have at least `N` elements"
+)]
+pub trait TListSplit<N: Unsigned>: TList {
+    #[doc(hidden)]
+    type Take: TList;
+    #[doc(hidden)]
+    type Skip: TList;
+
+    // This method is hidden and sealed as its not intended to be called by the user.
+    #[doc(hidden)]
+    fn _split_at(self, _key: seal::Key) -> SplitAt<Self, N>;
+}
+
+/// The [trait@TList] of the `N` first elements of `L`.
+pub type Take<L, N> = <L as TListSplit<N>>::Take;
+/// The [trait@TList] `L` without its `N` first elements.
+pub type Skip<L, N> = <L as TListSplit<N>>::Skip;
+/// The two parts of `L` split after its `N` first elements.
+pub type SplitAt<L, N> = (Take<L, N>, Skip<L, N>);
+
+// The index of the next element in canonical form.
+type Prev<N> = unsigned::RmExtraBits<unsigned::Dec<N>>;
+
+impl<L: TList> TListSplit<Last<B0>> for L {
+    type Take = End;
+    type Skip = L;
+
+    fn _split_at(self, _key: seal::Key) -> SplitAt<Self, Last<B0>> {
+        (End, self)
+    }
+}
+
+impl<H, T: TListSplit<Last<B0>>> TListSplit<Last<B1>> for Cat<H, T> {
+    type Take = Cat<H, Take<T, Last<B0>>>;
+    type Skip = Skip<T, Last<B0>>;
+
+    fn _split_at(self, _key: seal::Key) -> SplitAt<Self, Last<B1>> {
+        let (take, skip) = self.tail._split_at(seal::Key {});
+        (
+            Cat {
+                head: self.head,
+                tail: take,
+            },
+            skip,
+        )
+    }
+}
+
+impl<H, M: Unsigned, B: Bit, T: TListSplit<Prev<UInt<M, B>>>> TListSplit<UInt<M, B>> for Cat<H, T> {
+    type Take = Cat<H, Take<T, Prev<UInt<M, B>>>>;
+    type Skip = Skip<T, Prev<UInt<M, B>>>;
+
+    fn _split_at(self, _key: seal::Key) -> SplitAt<Self, UInt<M, B>> {
+        let (take, skip) = self.tail._split_at(seal::Key {});
+        (
+            Cat {
+                head: self.head,
+                tail: take,
+            },
+            skip,
+        )
+    }
+}
+
 /// A trait implemented for non empty [trait@TList].
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a non empty `TList`",
+    label = "this list may be empty"
+)]
 pub trait NonEmpty: TList + seal::Sealed {
     #[doc(hidden)]
     type Head;
@@ -228,6 +565,10 @@ pub type Tail<L> = <L as NonEmpty>::Tail;
 
 /// A trait to index the type list using type inference. It only works if the type list contains
 /// no duplicate types.
+#[diagnostic::on_unimplemented(
+    message = "`{T}` can't be found in `{Self}`",
+    note = "the list must contain `{T}` exactly once"
+)]
 pub trait TLFind<T, I> {
     fn find(&self) -> &T;
     fn find_mut(&mut self) -> &mut T;
@@ -276,8 +617,82 @@ impl<Head, T, TailIdx, Tail: TList + TLFind<T, TailIdx>> TLFind<T, There<TailIdx
     }
 }
 
+/// A trait to remove an element from the type list using type inference. It returns the element
+/// and the rest of the list. Like [trait@TLFind] it only works if the type list contains no
+/// duplicate types.
+pub trait TLPluck<T, I>: TList {
+    #[doc(hidden)]
+    type Remainder: TList;
+
+    fn pluck(self) -> (T, Self::Remainder);
+}
+
+/// The [trait@TList] `L` without the element of type `T` found at the inferred index `I`.
+pub type Remainder<L, T, I> = <L as TLPluck<T, I>>::Remainder;
+
+impl<T, Tail: TList> TLPluck<T, Here> for Cat<T, Tail> {
+    type Remainder = Tail;
+
+    fn pluck(self) -> (T, Self::Remainder) {
+        (self.head, self.tail)
+    }
+}
+
+impl<Head, T, TailIdx, Tail: TLPluck<T, TailIdx>> TLPluck<T, There<TailIdx>> for Cat<Head, Tail> {
+    type Remainder = Cat<Head, Tail::Remainder>;
+
+    fn pluck(self) -> (T, Self::Remainder) {
+        let (elem, tail) = self.tail.pluck();
+        (
+            elem,
+            Cat {
+                head: self.head,
+                tail,
+            },
+        )
+    }
+}
+
+/// A trait to reorder the type list into `Target` using type inference. The element types of
+/// `Target` must be found in the list, the elements that are not part of `Target` are returned as
+/// the leftover list. The `Indices` are the list of the inferred indices of [trait@TLPluck].
+pub trait TLSculpt<Target: TList, Indices>: TList {
+    #[doc(hidden)]
+    type Leftover: TList;
+
+    fn sculpt(self) -> (Target, Self::Leftover);
+}
+
+/// The elements of the [trait@TList] `L` not taken to build `Target`.
+pub type Leftover<L, Target, Indices> = <L as TLSculpt<Target, Indices>>::Leftover;
+
+impl<L: TList> TLSculpt<End, End> for L {
+    type Leftover = L;
+
+    fn sculpt(self) -> (End, Self::Leftover) {
+        (End, self)
+    }
+}
+
+impl<L, THead, TTail, HeadIdx, TailIdx> TLSculpt<Cat<THead, TTail>, Cat<HeadIdx, TailIdx>> for L
+where
+    L: TLPluck<THead, HeadIdx>,
+    TTail: TList,
+    TailIdx: TList,
+    Remainder<L, THead, HeadIdx>: TLSculpt<TTail, TailIdx>,
+{
+    type Leftover = Leftover<Remainder<L, THead, HeadIdx>, TTail, TailIdx>;
+
+    fn sculpt(self) -> (Cat<THead, TTail>, Self::Leftover) {
+        let (head, rest) = self.pluck();
+        let (tail, leftover) = rest.sculpt();
+        (Cat { head, tail }, leftover)
+    }
+}
+
 /// A trait that allows indexing into the Type List using an Unsigned type. OOB indexing returns
-/// Unit.
+/// [Invalid]. See [trait@TListGet] for indexing checked at compile time and `checked_index`
+/// for indexing returning a [trait@fallible::TOption] type.
 pub trait TListIndex<'a>: NonEmpty
 where
     Self: 'a,
@@ -286,9 +701,13 @@ where
     type Index<Idx: Unsigned>;
     #[doc(hidden)]
     type IndexMut<Idx: Unsigned>;
+    #[doc(hidden)]
+    type CheckedIndex<Idx: Unsigned>;
 
     fn index<Idx: Unsigned>(&'a self) -> Self::Index<Idx>;
     fn index_mut<Idx: Unsigned>(&'a mut self) -> Self::IndexMut<Idx>;
+    /// `Some(&elem)` if there is an element at index `Idx`, otherwise `None`.
+    fn checked_index<Idx: Unsigned>(&'a self) -> Self::CheckedIndex<Idx>;
 }
 impl<'a, H> TListIndex<'a> for Cat<H, End>
 where
@@ -299,22 +718,33 @@ where
         //Then
         &'a Head<Self>,
         //Else
-        Invalid,
+        Invalid<IndexOutOfBounds>,
     >;
     type IndexMut<Idx: Unsigned> = If<
         IsZero<Idx>,
         //Then
         &'a mut Head<Self>,
         //Else
-        Invalid,
+        Invalid<IndexOutOfBounds>,
+    >;
+    type CheckedIndex<Idx: Unsigned> = If<
+        IsZero<Idx>,
+        //Then
+        fallible::Some<&'a Head<Self>>,
+        //Else
+        fallible::None,
     >;
 
     fn index<Idx: Unsigned>(&'a self) -> Self::Index<Idx> {
-        IsZero::<Idx>::cond(|| &self.head, || Invalid)
+        IsZero::<Idx>::cond(|| &self.head, Invalid::default)
     }
 
     fn index_mut<Idx: Unsigned>(&'a mut self) -> Self::IndexMut<Idx> {
-        IsZero::<Idx>::cond(|| &mut self.head, || Invalid)
+        IsZero::<Idx>::cond(|| &mut self.head, Invalid::default)
+    }
+
+    fn checked_index<Idx: Unsigned>(&'a self) -> Self::CheckedIndex<Idx> {
+        IsZero::<Idx>::cond(|| fallible::Some(&self.head), || fallible::None)
     }
 }
 
@@ -338,6 +768,14 @@ where
         IndexMut<'a, Tail<Self>, unsigned::Dec<Idx>>,
     >;
 
+    type CheckedIndex<Idx: Unsigned> = If<
+        IsZero<Idx>,
+        //Then
+        fallible::Some<&'a Head<Self>>,
+        //Else
+        CheckedIndex<'a, Tail<Self>, unsigned::Dec<Idx>>,
+    >;
+
     fn index<Idx: Unsigned>(&'a self) -> Self::Index<Idx> {
         IsZero::<Idx>::cond(|| &self.head, || self.tail.index::<unsigned::Dec<Idx>>())
     }
@@ -348,17 +786,139 @@ where
             || self.tail.index_mut::<unsigned::Dec<Idx>>(),
         )
     }
+
+    fn checked_index<Idx: Unsigned>(&'a self) -> Self::CheckedIndex<Idx> {
+        IsZero::<Idx>::cond(
+            || fallible::Some(&self.head),
+            || self.tail.checked_index::<unsigned::Dec<Idx>>(),
+        )
+    }
 }
 
 /// The `&T` where `T` is at index `Idx` in the non empty [trait@TList] `L`.
 pub type Index<'a, L, Idx> = <L as TListIndex<'a>>::Index<Idx>;
 /// The `&mut T` where `T` is at index `Idx` in the non empty [trait@TList] `L`.
 pub type IndexMut<'a, L, Idx> = <L as TListIndex<'a>>::IndexMut<Idx>;
+/// `Some<&T>` where `T` is at index `Idx` in the non empty [trait@TList] `L` or `None` if `Idx` is
+/// out of bounds.
+pub type CheckedIndex<'a, L, Idx> = <L as TListIndex<'a>>::CheckedIndex<Idx>;
+
+// Only implemented by `True`: `Lt<Idx, Len<L>>` has to hold for `Idx` to be in the bounds of `L`.
+// The trait is in a private module so that it can't be implemented for `False`.
+mod bounds {
+    #[diagnostic::on_unimplemented(
+        message = "index `{Idx}` is out of bounds for `{L}`",
+        label = "the list is too short"
+    )]
+    pub trait InBounds<L, Idx> {}
+    impl<L, Idx> InBounds<L, Idx> for crate::bool::True {}
+}
+use bounds::InBounds;
+
+/// A trait that allows indexing into the Type List using an Unsigned type. Unlike
+/// [trait@TListIndex] OOB indexing doesn't compile.
+/// ```compile_fail
+/// use typers::{list::{Cat, End, TListGet}, num::unsigned::U4, tlist};
+/// let list = tlist![0i32, "Foo"];
+/// list.get::<U4>();
+/// ```
+pub trait TListGet<'a>: TListIndex<'a>
+where
+    Self: 'a,
+{
+    fn get<Idx: Unsigned>(&'a self) -> Self::Index<Idx>
+    where
+        unsigned::Lt<Idx, Len<Self>>: InBounds<Self, Idx>,
+    {
+        self.index::<Idx>()
+    }
+
+    fn get_mut<Idx: Unsigned>(&'a mut self) -> Self::IndexMut<Idx>
+    where
+        unsigned::Lt<Idx, Len<Self>>: InBounds<Self, Idx>,
+    {
+        self.index_mut::<Idx>()
+    }
+}
+
+impl<'a, L: TListIndex<'a>> TListGet<'a> for L {}
+
+/// A trait implemented by the tuples of up to 16 elements to convert them to the equivalent
+/// [trait@TList].
+pub trait IntoTList {
+    #[doc(hidden)]
+    type TList: TList;
+
+    fn into_tlist(self) -> Self::TList;
+}
+
+/// A trait implemented by the [trait@TList] of up to 16 elements to convert them to the
+/// equivalent tuple.
+pub trait IntoTuple: TList {
+    #[doc(hidden)]
+    type Tuple: IntoTList<TList = Self>;
+
+    fn into_tuple(self) -> Self::Tuple;
+}
+
+/// The [trait@TList] equivalent of the tuple `T`.
+pub type ToTList<T> = <T as IntoTList>::TList;
+/// The tuple equivalent of the [trait@TList] `L`.
+pub type ToTuple<L> = <L as IntoTuple>::Tuple;
+
+macro_rules! impl_tuple {
+    ($($T:ident),*) => {
+        impl<$($T),*> IntoTList for ($($T,)*) {
+            type TList = Tlist!($($T),*);
+
+            #[allow(non_snake_case)]
+            fn into_tlist(self) -> Self::TList {
+                let ($($T,)*) = self;
+                tlist!($($T),*)
+            }
+        }
+
+        impl<$($T),*> IntoTuple for Tlist!($($T),*) {
+            type Tuple = ($($T,)*);
+
+            #[allow(non_snake_case, clippy::unused_unit)]
+            fn into_tuple(self) -> Self::Tuple {
+                let tlist_pat!($($T),*) = self;
+                ($($T,)*)
+            }
+        }
+
+        impl<$($T),*> From<($($T,)*)> for Tlist!($($T),*) {
+            fn from(tuple: ($($T,)*)) -> Self {
+                tuple.into_tlist()
+            }
+        }
+
+        impl<$($T),*> From<Tlist!($($T),*)> for ($($T,)*) {
+            fn from(list: Tlist!($($T),*)) -> Self {
+                list.into_tuple()
+            }
+        }
+    };
+}
+
+macro_rules! impl_tuples {
+    ($T1:ident $(, $T2:ident)*) => {
+        impl_tuple!($T1 $(, $T2)*);
+        impl_tuples!($($T2),*);
+    };
+    () => {
+        impl_tuple!();
+    };
+}
+
+impl_tuples!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P);
 
 #[cfg(test)]
+#[allow(clippy::explicit_auto_deref)]
 mod test {
 
-    use crate::num::unsigned::{U1, U4};
+    use crate::num::unsigned::{U1, U12, U2, U3, U4, U5, U9};
 
     use super::*;
     #[test]
@@ -381,6 +941,54 @@ mod test {
         assert!(b == "Bar");
     }
 
+    #[test]
+    fn test_pluck() {
+        let list = tlist![5i32, "Foo", 'c'];
+        let (a, list): (&str, _) = list.pluck();
+        assert!(a == "Foo");
+        assert!(list.len() == 2);
+        let (b, list): (char, _) = list.pluck();
+        let (c, list): (i32, End) = list.pluck();
+        assert!(b == 'c');
+        assert!(c == 5);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_pluck_remainder_type() {
+        fn consume<I, L: TLPluck<u8, I, Remainder = Cat<i32, End>>>(list: L) -> u8 {
+            let (elem, rest) = list.pluck();
+            elem + rest.head as u8
+        }
+        assert!(consume(tlist![1u8, 2i32]) == 3);
+        assert!(consume(tlist![2i32, 1u8]) == 3);
+    }
+
+    #[test]
+    fn test_sculpt() {
+        let list = tlist![5i32, "Foo", 'c', 1u8];
+        let (target, leftover): (Cat<char, Cat<i32, End>>, _) = list.sculpt();
+        assert!(target.head == 'c');
+        assert!(target.tail.head == 5);
+        let a: &str = *leftover.find();
+        let b: u8 = *leftover.find();
+        assert!(a == "Foo");
+        assert!(b == 1);
+        assert!(leftover.len() == 2);
+    }
+
+    #[test]
+    fn test_sculpt_permutation() {
+        fn reorder<I, L: TLSculpt<Cat<u8, Cat<&'static str, End>>, I, Leftover = End>>(
+            list: L,
+        ) -> (u8, &'static str) {
+            let (target, End) = list.sculpt();
+            (target.head, target.tail.head)
+        }
+        assert!(reorder(tlist!["Foo", 1u8]) == (1, "Foo"));
+        assert!(reorder(tlist![1u8, "Foo"]) == (1, "Foo"));
+    }
+
     #[test]
     fn test_index_as_type_parameter() {
         fn foo<I, L: TLFind<i32, I>>(list: &L) -> i32 {
@@ -401,6 +1009,192 @@ mod test {
         assert!(b == "Foo");
     }
 
+    struct Optional;
+    impl TypeFn for Optional {
+        type Output<T> = Option<T>;
+    }
+    impl<T> Mapper<T> for Optional {
+        fn map(&mut self, elem: T) -> Option<T> {
+            Some(elem)
+        }
+    }
+
+    // Numbers the elements of the list.
+    struct Enumerate(usize);
+    impl TypeFn for Enumerate {
+        type Output<T> = (usize, T);
+    }
+    impl<T> Mapper<T> for Enumerate {
+        fn map(&mut self, elem: T) -> (usize, T) {
+            self.0 += 1;
+            (self.0 - 1, elem)
+        }
+    }
+
+    #[test]
+    fn test_tlist_map() {
+        let list: Map<Cat<i32, Cat<&str, End>>, Optional> = tlist![1i32, "Foo"].map(Optional);
+        let a: Option<i32> = *list.find();
+        let b: Option<&str> = *list.find();
+        assert!(a == Some(1));
+        assert!(b == Some("Foo"));
+        assert!(End.map(Optional).is_empty());
+    }
+
+    #[test]
+    fn test_tlist_map_state() {
+        let list = tlist![1i32, "Foo", 'c'].map(Enumerate(0));
+        let a: (usize, i32) = *list.find();
+        let b: (usize, &str) = *list.find();
+        let c: (usize, char) = *list.find();
+        assert!(a == (0, 1i32));
+        assert!(b == (1, "Foo"));
+        assert!(c == (2, 'c'));
+    }
+
+    struct Sum;
+    impl<Acc: Unsigned, T: Unsigned> TypeFn2<Acc, T> for Sum {
+        type Output = unsigned::Add<Acc, T>;
+    }
+
+    #[test]
+    fn test_tlist_fold_unsigned() {
+        type Numbers = Cat<U1, Cat<U5, Cat<U9, End>>>;
+        assert_eq!(<FoldL<Numbers, Sum, U0> as Unsigned>::USIZE, 15);
+        assert_eq!(<FoldR<Numbers, Sum, U12> as Unsigned>::USIZE, 27);
+        assert_eq!(<FoldL<End, Sum, U4> as Unsigned>::USIZE, 4);
+    }
+
+    // Writes the elements in a string.
+    struct Show;
+    impl<T> TypeFn2<String, T> for Show {
+        type Output = String;
+    }
+    impl<T: std::fmt::Display> Folder<String, T> for Show {
+        fn fold(&mut self, acc: String, elem: T) -> String {
+            format!("{acc}{elem}")
+        }
+    }
+
+    #[test]
+    fn test_tlist_fold() {
+        let list = tlist![1i32, "Foo", 'c'];
+        assert!(list.fold(Show, String::new()) == "1Fooc");
+        let list = tlist![1i32, "Foo", 'c'];
+        assert!(list.rfold(Show, String::new()) == "cFoo1");
+        assert!(End.fold(Show, String::from("empty")) == "empty");
+    }
+
+    #[test]
+    fn test_tlist_zip() {
+        let list = tlist![1i32, "Foo"].zip(tlist!['c', 2.5f32]);
+        let a: (i32, char) = *list.find();
+        let b: (&str, f32) = *list.find();
+        assert!(a == (1, 'c'));
+        assert!(b == ("Foo", 2.5));
+
+        let (left, right) = list.unzip();
+        let a: i32 = *left.find();
+        let b: f32 = *right.find();
+        assert!(a == 1);
+        assert!(b == 2.5);
+        assert!(left.len() == 2 && right.len() == 2);
+    }
+
+    #[test]
+    fn test_tlist_zip_len_mismatch() {
+        fn is_invalid<L: 'static>() -> bool {
+            std::any::TypeId::of::<L>() == std::any::TypeId::of::<Invalid<LengthMismatch>>()
+        }
+        assert!(is_invalid::<Zip<Cat<i32, End>, End>>());
+        assert!(is_invalid::<Zip<End, Cat<i32, End>>>());
+        assert!(is_invalid::<Zip<Cat<i32, Cat<u8, End>>, Cat<char, End>>>());
+        assert!(!is_invalid::<Zip<Cat<i32, End>, Cat<char, End>>>());
+    }
+
+    #[test]
+    fn test_tuple_conversion() {
+        let list: Tlist![i32, &str, char] = (5i32, "Foo", 'c').into_tlist();
+        let a: i32 = *list.find();
+        let b: &str = *list.find();
+        assert!(a == 5 && b == "Foo");
+        assert!(list.into_tuple() == (5, "Foo", 'c'));
+
+        let list: ToTList<(u8,)> = (1u8,).into();
+        let tuple: (u8,) = list.into();
+        assert!(tuple == (1,));
+
+        let list: End = ().into();
+        let () = list.into_tuple();
+    }
+
+    #[test]
+    fn test_tuple_conversion_16() {
+        type Tuple = (
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u16,
+        );
+        let tuple: Tuple = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+        let list = tuple.into_tlist();
+        assert!(list.len() == 16);
+        assert!(*list.get::<U4>() == 4);
+        let last: u16 = *list.find();
+        assert!(last == 15);
+        let tuple: ToTuple<ToTList<Tuple>> = list.into();
+        assert!(tuple.0 == 0 && tuple.7 == 7 && tuple.15 == 15);
+    }
+
+    #[test]
+    fn test_std_traits() {
+        use std::collections::HashSet;
+
+        let list = tlist![1i32, "Foo", 'c'];
+        let copy = list;
+        assert!(list == copy);
+        let owned = tlist![1i32, String::from("Foo")];
+        assert!(owned.clone() == owned);
+        assert!(list != tlist![1i32, "Foo", 'd']);
+        assert!(End == End);
+
+        let default: Tlist![i32, String, Option<u8>] = Default::default();
+        assert!(default == tlist![0, String::new(), None]);
+
+        let mut set = HashSet::new();
+        set.insert(tlist![1i32, 'c']);
+        set.insert(tlist![1i32, 'c']);
+        set.insert(tlist![2i32, 'c']);
+        assert!(set.len() == 2);
+    }
+
+    #[test]
+    fn test_lexicographic_order() {
+        use std::cmp::Ordering;
+
+        assert!(tlist![1i32, 'z'] < tlist![2i32, 'a']);
+        assert!(tlist![1i32, 'a'] < tlist![1i32, 'b']);
+        assert!(tlist![1i32, "Foo"].cmp(&tlist![1i32, "Foo"]) == Ordering::Equal);
+        assert!(tlist![1.0f32, 2]
+            .partial_cmp(&tlist![f32::NAN, 1])
+            .is_none());
+        let mut lists = vec![tlist![2u8, 'a'], tlist![1u8, 'b'], tlist![1u8, 'a']];
+        lists.sort();
+        assert!(lists == vec![tlist![1u8, 'a'], tlist![1u8, 'b'], tlist![2u8, 'a']]);
+    }
+
     #[test]
     fn test_tlist_index() {
         let mut list = dbg!(tlist![2i32]);
@@ -411,6 +1205,63 @@ mod test {
         assert!(list.index::<U0>() == &5i32);
     }
 
+    #[test]
+    fn test_tlist_get() {
+        let mut list = tlist![0i32, "Foo", 'c'];
+        *list.get_mut::<U2>() = 'd';
+        let a: i32 = *list.get::<U0>();
+        let b: &str = *list.get::<U1>();
+        let c: char = *list.get::<U2>();
+        assert!(a == 0i32);
+        assert!(b == "Foo");
+        assert!(c == 'd');
+    }
+
+    #[test]
+    fn test_checked_index() {
+        let list = tlist![0i32, "Foo", 'c'];
+        assert_eq!(list.checked_index::<U0>(), fallible::Some(&0i32));
+        assert_eq!(list.checked_index::<U2>(), fallible::Some(&'c'));
+        assert_eq!(list.checked_index::<U3>(), fallible::None);
+        assert_eq!(list.checked_index::<U12>(), fallible::None);
+        type List = Tlist![i32, &'static str, char];
+        crate::assert_type_eq!(
+            fallible::Unwrap<CheckedIndex<'static, List, U1>>,
+            &'static &'static str
+        );
+        crate::static_assert!(fallible::IsNone<CheckedIndex<'static, List, U5>>);
+    }
+
+    #[test]
+    fn test_static_assert() {
+        type List = Tlist![i32, &'static str, char];
+        crate::static_assert!(unsigned::Lt<Len<List>, U4>);
+        crate::static_assert!(unsigned::Eq<Len<Take<List, U2>>, U2>);
+        crate::assert_type_eq!(Skip<List, U1>, Tlist![&'static str, char]);
+        crate::assert_type_eq!(Remainder<List, char, There<There<Here>>>, Tlist![i32, &'static str]);
+    }
+
+    #[test]
+    fn test_tlist_split() {
+        let list = tlist![0i32, "Foo", 'c', 1u8];
+        let (take, skip) = list.split_at::<U3>();
+        let a: i32 = *take.find();
+        let b: &str = *take.find();
+        let c: char = *take.find();
+        let d: u8 = *skip.find();
+        assert!((a, b, c, d) == (0, "Foo", 'c', 1));
+        assert!(take.len() == 3 && skip.len() == 1);
+
+        let list = tlist![0i32, "Foo", 'c', 1u8];
+        assert!(list.take::<U0>().is_empty());
+        let list = tlist![0i32, "Foo", 'c', 1u8];
+        assert!(list.skip::<U4>().is_empty());
+        type List = Cat<i32, Cat<&'static str, Cat<char, Cat<u8, End>>>>;
+        let list: List = tlist![0i32, "Foo", 'c', 1u8];
+        let skip: Skip<List, U1> = list.skip::<U1>();
+        assert!(*skip.get::<U0>() == "Foo");
+    }
+
     #[test]
     #[should_panic]
     fn test_tlist_index_oob() {
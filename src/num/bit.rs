@@ -1,5 +1,7 @@
 use crate::{
     bool::{Bool, False, True},
+    cmp::{Equal, Greater, Less, Ordering},
+    invalid::ErrorTag,
     seal, Invalid,
 };
 
@@ -14,6 +16,10 @@ impl seal::Sealed for B0 {}
 impl seal::Sealed for B1 {}
 
 /// A trait representing all valid operation for a  bit.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a type level bit",
+    note = "type level bits are `B0` and `B1`"
+)]
 pub trait Bit: seal::Sealed {
     /// Bit value.
     const USIZE: usize;
@@ -58,6 +64,10 @@ pub trait Bit: seal::Sealed {
     #[doc(hidden)]
     /// Borrow of Substraction with borrow in input.
     type FullBorrow<Rhs: Bit, B: Bit>: Bit;
+
+    #[doc(hidden)]
+    /// Comparison.
+    type Cmp<Rhs: Bit>: Ordering;
 }
 
 /// Short Hand type function.
@@ -89,35 +99,40 @@ pub type FullDiff<Lhs, Rhs, C> = <Lhs as Bit>::FullDiff<Rhs, C>;
 /// Short Hand type function.
 pub type FullBorrow<Lhs, Rhs, C> = <Lhs as Bit>::FullBorrow<Rhs, C>;
 
-impl Bit for Invalid {
+/// Short Hand type function.
+pub type Cmp<Lhs, Rhs> = <Lhs as Bit>::Cmp<Rhs>;
+
+impl<Tag: ErrorTag> Bit for Invalid<Tag> {
     #[doc(hidden)]
-    const USIZE: usize = { panic!("Invlid Bit type value!") };
+    const USIZE: usize = { panic!("{}", Tag::REASON) };
+
+    type IsZero = Self;
 
-    type IsZero = Invalid;
+    type Not = Self;
 
-    type Not = Invalid;
+    type And<Rhs: Bit> = Self;
 
-    type And<Rhs: Bit> = Invalid;
+    type Or<Rhs: Bit> = Self;
 
-    type Or<Rhs: Bit> = Invalid;
+    type Xor<Rhs: Bit> = Self;
 
-    type Xor<Rhs: Bit> = Invalid;
+    type Add<Rhs: Bit> = Self;
 
-    type Add<Rhs: Bit> = Invalid;
+    type Carry<Rhs: Bit> = Self;
 
-    type Carry<Rhs: Bit> = Invalid;
+    type FullAdd<Rhs: Bit, C: Bit> = Self;
 
-    type FullAdd<Rhs: Bit, C: Bit> = Invalid;
+    type FullCarry<Rhs: Bit, C: Bit> = Self;
 
-    type FullCarry<Rhs: Bit, C: Bit> = Invalid;
+    type Diff<Rhs: Bit> = Self;
 
-    type Diff<Rhs: Bit> = Invalid;
+    type Borrow<Rhs: Bit> = Self;
 
-    type Borrow<Rhs: Bit> = Invalid;
+    type FullDiff<Rhs: Bit, B: Bit> = Self;
 
-    type FullDiff<Rhs: Bit, B: Bit> = Invalid;
+    type FullBorrow<Rhs: Bit, B: Bit> = Self;
 
-    type FullBorrow<Rhs: Bit, B: Bit> = Invalid;
+    type Cmp<Rhs: Bit> = Self;
 }
 
 impl Bit for B0 {
@@ -137,14 +152,16 @@ impl Bit for B0 {
     type Borrow<Rhs: Bit> = Rhs;
     type FullDiff<Rhs: Bit, B: Bit> = Rhs::Xor<B>;
     type FullBorrow<Rhs: Bit, B: Bit> = Rhs::Or<B>;
+
+    type Cmp<Rhs: Bit> = <Rhs::IsZero as Bool>::Iford<Equal, Less>;
 }
 
 impl Bit for B1 {
     const USIZE: usize = 1;
     type IsZero = False;
     type Not = B0;
-    type And<Rhs: Bit> = B0;
-    type Or<Rhs: Bit> = Rhs;
+    type And<Rhs: Bit> = Rhs;
+    type Or<Rhs: Bit> = B1;
     type Xor<Rhs: Bit> = Rhs::Not;
 
     type Add<Rhs: Bit> = Self::Xor<Rhs>;
@@ -159,4 +176,27 @@ impl Bit for B1 {
     type FullDiff<Rhs: Bit, B: Bit> = <Self::Diff<Rhs> as Bit>::Diff<B>;
     type FullBorrow<Rhs: Bit, B: Bit> =
         <Self::Borrow<Rhs> as Bit>::Xor<<Self::Diff<Rhs> as Bit>::Borrow<B>>;
+
+    type Cmp<Rhs: Bit> = <Rhs::IsZero as Bool>::Iford<Greater, Equal>;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn test_truth_table<L: Bit, R: Bit>() {
+        assert_eq!(<L::And<R> as Bit>::USIZE, L::USIZE & R::USIZE);
+        assert_eq!(<L::Or<R> as Bit>::USIZE, L::USIZE | R::USIZE);
+        assert_eq!(<L::Xor<R> as Bit>::USIZE, L::USIZE ^ R::USIZE);
+    }
+
+    #[test]
+    fn test_logic() {
+        test_truth_table::<B0, B0>();
+        test_truth_table::<B0, B1>();
+        test_truth_table::<B1, B0>();
+        test_truth_table::<B1, B1>();
+        assert_eq!(<B0 as Bit>::Not::USIZE, 1);
+        assert_eq!(<B1 as Bit>::Not::USIZE, 0);
+    }
 }
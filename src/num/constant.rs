@@ -0,0 +1,109 @@
+//! Bridge between `usize` const generics and [trait@Unsigned] types.
+use crate::{num::unsigned::Unsigned, seal};
+
+/// A `usize` const generic as a type.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Const<const N: usize>;
+impl<const N: usize> seal::Sealed for Const<N> {}
+
+/// A trait implemented by [struct@Const] for `N` in `0..=256` mapping it to its [trait@Unsigned]
+/// representation.
+pub trait IntoUnsigned: seal::Sealed {
+    #[doc(hidden)]
+    type Unsigned: Unsigned;
+}
+
+/// The reverse of [trait@IntoUnsigned]. Implemented by the canonical [trait@Unsigned] types of
+/// `0..=256`.
+///
+/// Outside of generic code `Unsigned::USIZE` can be used directly as an array length:
+/// `[T; U8::USIZE]`. Generic code has to go through [type@Array] as the compiler doesn't allow
+/// array lengths depending on a generic parameter.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` has no `Const` equivalent",
+    note = "only the canonical unsigned numbers from 0 to 256 have one"
+)]
+pub trait ToConst: Unsigned {
+    #[doc(hidden)]
+    type Const: IntoUnsigned;
+    #[doc(hidden)]
+    type Array<T>;
+}
+
+/// The [trait@Unsigned] equivalent of `Const<N>`.
+pub type UnsignedOf<C> = <C as IntoUnsigned>::Unsigned;
+/// The [struct@Const] equivalent of `U`.
+pub type ConstOf<U> = <U as ToConst>::Const;
+/// An array of `T` of length `U`.
+pub type Array<T, U> = <U as ToConst>::Array<T>;
+
+macro_rules! impl_const {
+    ($($n:literal),*) => {
+        $(
+            impl IntoUnsigned for Const<$n> {
+                type Unsigned = crate::U![$n];
+            }
+
+            impl ToConst for crate::U![$n] {
+                type Const = Const<$n>;
+                type Array<T> = [T; $n];
+            }
+        )*
+    };
+}
+
+impl_const!(
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25,
+    26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49,
+    50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64, 65, 66, 67, 68, 69, 70, 71, 72, 73,
+    74, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 92, 93, 94, 95, 96, 97,
+    98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 109, 110, 111, 112, 113, 114, 115, 116,
+    117, 118, 119, 120, 121, 122, 123, 124, 125, 126, 127, 128, 129, 130, 131, 132, 133, 134, 135,
+    136, 137, 138, 139, 140, 141, 142, 143, 144, 145, 146, 147, 148, 149, 150, 151, 152, 153, 154,
+    155, 156, 157, 158, 159, 160, 161, 162, 163, 164, 165, 166, 167, 168, 169, 170, 171, 172, 173,
+    174, 175, 176, 177, 178, 179, 180, 181, 182, 183, 184, 185, 186, 187, 188, 189, 190, 191, 192,
+    193, 194, 195, 196, 197, 198, 199, 200, 201, 202, 203, 204, 205, 206, 207, 208, 209, 210, 211,
+    212, 213, 214, 215, 216, 217, 218, 219, 220, 221, 222, 223, 224, 225, 226, 227, 228, 229, 230,
+    231, 232, 233, 234, 235, 236, 237, 238, 239, 240, 241, 242, 243, 244, 245, 246, 247, 248, 249,
+    250, 251, 252, 253, 254, 255, 256
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::num::unsigned::{Mul, U0, U16, U4, U8};
+
+    fn test_round_trip<const N: usize>()
+    where
+        Const<N>: IntoUnsigned,
+        UnsignedOf<Const<N>>: ToConst<Const = Const<N>>,
+    {
+        assert_eq!(<UnsignedOf<Const<N>> as Unsigned>::USIZE, N);
+        assert_eq!(std::mem::size_of::<Array<u8, UnsignedOf<Const<N>>>>(), N);
+    }
+
+    #[test]
+    fn test_const() {
+        test_round_trip::<0>();
+        test_round_trip::<1>();
+        test_round_trip::<17>();
+        test_round_trip::<64>();
+        test_round_trip::<255>();
+        test_round_trip::<256>();
+    }
+
+    #[test]
+    fn test_array() {
+        fn zeros<U: ToConst>() -> Array<u32, U>
+        where
+            Array<u32, U>: Default,
+        {
+            Default::default()
+        }
+        let a: Array<u32, Mul<U4, U4>> = zeros::<U16>();
+        assert_eq!(a, [0; 16]);
+        let b: [u8; U8::USIZE] = [0; 8];
+        assert_eq!(b.len(), 8);
+        assert_eq!(std::mem::size_of::<Array<u64, U0>>(), 0);
+    }
+}
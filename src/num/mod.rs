@@ -1,4 +1,6 @@
 #![doc = include_str!("./num.md")]
 
 pub mod bit;
+pub mod constant;
+pub mod signed;
 pub mod unsigned;
@@ -0,0 +1,303 @@
+use crate::{
+    bool::{self, Bool, False, True},
+    invalid::{ErrorTag, Negative},
+    num::unsigned::{
+        self, Unsigned, U0, U1, U10, U11, U12, U13, U14, U15, U16, U17, U18, U19, U2, U20, U21,
+        U22, U23, U24, U25, U26, U27, U28, U29, U3, U30, U31, U32, U4, U5, U6, U7, U8, U9,
+    },
+    seal, Invalid,
+};
+use std::marker;
+
+pub type If<C, A, B> = <C as Bool>::Ifint<A, B>;
+
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a type level signed number",
+    note = "type level signed numbers are `Z0`, `PInt<U>` and `NInt<U>`"
+)]
+pub trait Signed: seal::Sealed {
+    /// The value equivalent of `Self`
+    const ISIZE: isize;
+
+    #[doc(hidden)]
+    type IsZero: Bool;
+    #[doc(hidden)]
+    type IsPositive: Bool;
+    #[doc(hidden)]
+    type IsNegative: Bool;
+
+    #[doc(hidden)]
+    type Neg: Signed;
+    #[doc(hidden)]
+    type Abs: Signed;
+    #[doc(hidden)]
+    type ToUnsigned: Unsigned;
+
+    #[doc(hidden)]
+    type Add<Rhs: Signed>: Signed;
+    #[doc(hidden)]
+    type Sub<Rhs: Signed>: Signed;
+    #[doc(hidden)]
+    type Mul<Rhs: Signed>: Signed;
+
+    #[doc(hidden)]
+    /// Result of adding the positive number `U` to `Self`.
+    type AddPos<U: Unsigned>: Signed;
+    #[doc(hidden)]
+    /// Result of adding the negative number `-U` to `Self`.
+    type AddNeg<U: Unsigned>: Signed;
+    #[doc(hidden)]
+    /// Result of multiplying `Self` with the positive number `U`.
+    type MulPos<U: Unsigned>: Signed;
+}
+
+/// Check if the Signed is zero.
+pub type IsZero<S> = <S as Signed>::IsZero;
+/// Check if the Signed is strictly positive.
+pub type IsPositive<S> = <S as Signed>::IsPositive;
+/// Check if the Signed is strictly negative.
+pub type IsNegative<S> = <S as Signed>::IsNegative;
+/// Check if the Signed is zero or positive.
+pub type IsNonNegative<S> = bool::Not<IsNegative<S>>;
+/// The result of negating `Self`.
+pub type Neg<S> = <S as Signed>::Neg;
+/// The absolute value of `Self`.
+pub type Abs<S> = <S as Signed>::Abs;
+/// The [trait@Unsigned] equivalent of `Self`. Negative numbers are [Invalid].
+pub type ToUnsigned<S> = <S as Signed>::ToUnsigned;
+/// The [trait@Signed] equivalent of the [trait@Unsigned] `U`.
+pub type FromUnsigned<U> =
+    If<unsigned::IsZero<U>, /*Then*/ Z0, /*Else*/ PInt<unsigned::RmExtraBits<U>>>;
+/// Result of adding `Self` with `Rhs`.
+pub type Add<Lhs, Rhs> = <Lhs as Signed>::Add<Rhs>;
+/// Result of substracting `Self` with `Rhs`.
+pub type Sub<Lhs, Rhs> = <Lhs as Signed>::Sub<Rhs>;
+/// Result of multiplying `Self` with `Rhs`.
+pub type Mul<Lhs, Rhs> = <Lhs as Signed>::Mul<Rhs>;
+
+// The signed result of `A - B` for two unsigned numbers.
+type Diff<A, B> = If<
+    unsigned::Gt<A, B>,
+    /*Then*/ PInt<unsigned::Sub<A, B>>,
+    /*Else*/
+    If<unsigned::Lt<A, B>, /*Then*/ NInt<unsigned::Sub<B, A>>, /*Else*/ Z0>,
+>;
+
+/// The integer `0`.
+#[derive(Default)]
+pub struct Z0;
+impl seal::Sealed for Z0 {}
+
+/// The positive integer `U`. `U` should not be zero.
+#[derive(Default)]
+pub struct PInt<U: Unsigned>(marker::PhantomData<U>);
+impl<U: Unsigned> seal::Sealed for PInt<U> {}
+
+/// The negative integer `-U`. `U` should not be zero.
+#[derive(Default)]
+pub struct NInt<U: Unsigned>(marker::PhantomData<U>);
+impl<U: Unsigned> seal::Sealed for NInt<U> {}
+
+impl<Tag: ErrorTag> Signed for Invalid<Tag> {
+    #[doc(hidden)]
+    const ISIZE: isize = { panic!("{}", Tag::REASON) };
+
+    type IsZero = Self;
+    type IsPositive = Self;
+    type IsNegative = Self;
+
+    type Neg = Self;
+    type Abs = Self;
+    type ToUnsigned = Self;
+
+    type Add<Rhs: Signed> = Self;
+    type Sub<Rhs: Signed> = Self;
+    type Mul<Rhs: Signed> = Self;
+
+    type AddPos<U: Unsigned> = Self;
+    type AddNeg<U: Unsigned> = Self;
+    type MulPos<U: Unsigned> = Self;
+}
+
+impl Signed for Z0 {
+    const ISIZE: isize = 0;
+
+    type IsZero = True;
+    type IsPositive = False;
+    type IsNegative = False;
+
+    type Neg = Self;
+    type Abs = Self;
+    type ToUnsigned = U0;
+
+    type Add<Rhs: Signed> = Rhs;
+    type Sub<Rhs: Signed> = Rhs::Neg;
+    type Mul<Rhs: Signed> = Self;
+
+    type AddPos<U: Unsigned> = PInt<U>;
+    type AddNeg<U: Unsigned> = NInt<U>;
+    type MulPos<U: Unsigned> = Self;
+}
+
+impl<U_: Unsigned> Signed for PInt<U_> {
+    const ISIZE: isize = U_::USIZE as isize;
+
+    type IsZero = False;
+    type IsPositive = True;
+    type IsNegative = False;
+
+    type Neg = NInt<U_>;
+    type Abs = Self;
+    type ToUnsigned = U_;
+
+    type Add<Rhs: Signed> = Rhs::AddPos<U_>;
+    type Sub<Rhs: Signed> = Add<Self, Rhs::Neg>;
+    type Mul<Rhs: Signed> = Rhs::MulPos<U_>;
+
+    type AddPos<U: Unsigned> = PInt<unsigned::Add<U_, U>>;
+    type AddNeg<U: Unsigned> = Diff<U_, U>;
+    type MulPos<U: Unsigned> = PInt<unsigned::Mul<U_, U>>;
+}
+
+impl<U_: Unsigned> Signed for NInt<U_> {
+    const ISIZE: isize = -(U_::USIZE as isize);
+
+    type IsZero = False;
+    type IsPositive = False;
+    type IsNegative = True;
+
+    type Neg = PInt<U_>;
+    type Abs = PInt<U_>;
+    type ToUnsigned = Invalid<Negative>;
+
+    type Add<Rhs: Signed> = Rhs::AddNeg<U_>;
+    type Sub<Rhs: Signed> = Add<Self, Rhs::Neg>;
+    type Mul<Rhs: Signed> = Neg<Rhs::MulPos<U_>>;
+
+    type AddPos<U: Unsigned> = Diff<U, U_>;
+    type AddNeg<U: Unsigned> = NInt<unsigned::Add<U_, U>>;
+    type MulPos<U: Unsigned> = NInt<unsigned::Mul<U_, U>>;
+}
+
+pub type P1 = PInt<U1>;
+pub type P2 = PInt<U2>;
+pub type P3 = PInt<U3>;
+pub type P4 = PInt<U4>;
+pub type P5 = PInt<U5>;
+pub type P6 = PInt<U6>;
+pub type P7 = PInt<U7>;
+pub type P8 = PInt<U8>;
+pub type P9 = PInt<U9>;
+pub type P10 = PInt<U10>;
+pub type P11 = PInt<U11>;
+pub type P12 = PInt<U12>;
+pub type P13 = PInt<U13>;
+pub type P14 = PInt<U14>;
+pub type P15 = PInt<U15>;
+pub type P16 = PInt<U16>;
+pub type P17 = PInt<U17>;
+pub type P18 = PInt<U18>;
+pub type P19 = PInt<U19>;
+pub type P20 = PInt<U20>;
+pub type P21 = PInt<U21>;
+pub type P22 = PInt<U22>;
+pub type P23 = PInt<U23>;
+pub type P24 = PInt<U24>;
+pub type P25 = PInt<U25>;
+pub type P26 = PInt<U26>;
+pub type P27 = PInt<U27>;
+pub type P28 = PInt<U28>;
+pub type P29 = PInt<U29>;
+pub type P30 = PInt<U30>;
+pub type P31 = PInt<U31>;
+pub type P32 = PInt<U32>;
+
+pub type N1 = NInt<U1>;
+pub type N2 = NInt<U2>;
+pub type N3 = NInt<U3>;
+pub type N4 = NInt<U4>;
+pub type N5 = NInt<U5>;
+pub type N6 = NInt<U6>;
+pub type N7 = NInt<U7>;
+pub type N8 = NInt<U8>;
+pub type N9 = NInt<U9>;
+pub type N10 = NInt<U10>;
+pub type N11 = NInt<U11>;
+pub type N12 = NInt<U12>;
+pub type N13 = NInt<U13>;
+pub type N14 = NInt<U14>;
+pub type N15 = NInt<U15>;
+pub type N16 = NInt<U16>;
+pub type N17 = NInt<U17>;
+pub type N18 = NInt<U18>;
+pub type N19 = NInt<U19>;
+pub type N20 = NInt<U20>;
+pub type N21 = NInt<U21>;
+pub type N22 = NInt<U22>;
+pub type N23 = NInt<U23>;
+pub type N24 = NInt<U24>;
+pub type N25 = NInt<U25>;
+pub type N26 = NInt<U26>;
+pub type N27 = NInt<U27>;
+pub type N28 = NInt<U28>;
+pub type N29 = NInt<U29>;
+pub type N30 = NInt<U30>;
+pub type N31 = NInt<U31>;
+pub type N32 = NInt<U32>;
+
+#[cfg(test)]
+#[allow(clippy::assertions_on_constants)]
+mod test {
+    use super::*;
+    use crate::num::unsigned::test::cross;
+
+    fn test_pair<A: Signed, B: Signed>() {
+        assert_eq!(A::ISIZE, B::ISIZE);
+    }
+
+    fn test_ops<A: Signed, B: Signed>() {
+        assert_eq!(Add::<A, B>::ISIZE, A::ISIZE + B::ISIZE);
+        assert_eq!(Sub::<A, B>::ISIZE, A::ISIZE - B::ISIZE);
+        assert_eq!(Mul::<A, B>::ISIZE, A::ISIZE * B::ISIZE);
+    }
+
+    #[test]
+    fn test_values() {
+        test_pair::<Neg<P5>, N5>();
+        test_pair::<Neg<N5>, P5>();
+        test_pair::<Neg<Z0>, Z0>();
+        test_pair::<Abs<N7>, P7>();
+        test_pair::<Abs<P7>, P7>();
+        assert_eq!(N32::ISIZE, -32);
+        assert_eq!(P32::ISIZE, 32);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        cross!(
+            test_ops;
+            N32, N17, N8, N3, N1, Z0, P1, P2, P5, P16, P31;
+            [N32, N9, N4, N2, N1, Z0, P1, P3, P8, P13, P32]
+        );
+    }
+
+    #[test]
+    fn test_sign() {
+        assert!(IsNegative::<N3>::BOOL);
+        assert!(!IsNegative::<Z0>::BOOL);
+        assert!(IsPositive::<P3>::BOOL);
+        assert!(!IsPositive::<N3>::BOOL);
+        assert!(IsZero::<Sub<P3, P3>>::BOOL);
+        assert!(IsNonNegative::<Z0>::BOOL);
+    }
+
+    #[test]
+    fn test_unsigned_conversion() {
+        assert_eq!(ToUnsigned::<P12>::USIZE, 12);
+        assert_eq!(ToUnsigned::<Z0>::USIZE, 0);
+        test_pair::<FromUnsigned<U12>, P12>();
+        test_pair::<FromUnsigned<U0>, Z0>();
+        test_pair::<FromUnsigned<ToUnsigned<P9>>, P9>();
+        crate::assert_type_eq!(ToUnsigned<N4>, Invalid<Negative>);
+    }
+}
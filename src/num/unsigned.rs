@@ -1,5 +1,10 @@
 use crate::{
     bool::{self, Bool},
+    cmp::{self, Less, Ordering},
+    fallible,
+    func::TypeFn2,
+    invalid::{DivByZero, ErrorTag, InvalidBounds, LogOfZero, Underflow},
+    list,
     num::bit::{self, Bit, B0, B1},
     seal, Invalid,
 };
@@ -7,6 +12,10 @@ use std::marker;
 
 pub type If<C, A, B> = <C as Bool>::Ifuint<A, B>;
 
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a type level unsigned number",
+    note = "type level unsigned numbers are built from `UInt` and `Last`, for example with the `U!` macro"
+)]
 pub trait Unsigned: seal::Sealed {
     /// The value equivalent of `Self`
     const USIZE: usize;
@@ -37,8 +46,60 @@ pub trait Unsigned: seal::Sealed {
     type IsZero: Bool;
     #[doc(hidden)]
     type RmExtraBits: Unsigned;
+    // `UInt<Self, B>` in canonical form for a canonical `Self`.
+    #[doc(hidden)]
+    type _PushLsb<B: Bit>: Unsigned;
     #[doc(hidden)]
     type Mul<Rhs: Unsigned>: Unsigned;
+    #[doc(hidden)]
+    type SubBorrow<Rhs: Unsigned, B: Bit>: Bit;
+    #[doc(hidden)]
+    type Div<Rhs: Unsigned>: Unsigned;
+    #[doc(hidden)]
+    type Rem<Rhs: Unsigned>: Unsigned;
+    #[doc(hidden)]
+    type _Div<Rhs: Unsigned>: Unsigned;
+    #[doc(hidden)]
+    type _Rem<Rhs: Unsigned>: Unsigned;
+    #[doc(hidden)]
+    type Cmp<Rhs: Unsigned>: Ordering;
+    #[doc(hidden)]
+    type And<Rhs: Unsigned>: Unsigned;
+    #[doc(hidden)]
+    type Or<Rhs: Unsigned>: Unsigned;
+    #[doc(hidden)]
+    type Xor<Rhs: Unsigned>: Unsigned;
+    #[doc(hidden)]
+    type Shl<N: Unsigned>: Unsigned;
+    #[doc(hidden)]
+    type Shr<N: Unsigned>: Unsigned;
+    #[doc(hidden)]
+    type _ShlOf<U: Unsigned>: Unsigned;
+    #[doc(hidden)]
+    type Pow<E: Unsigned>: Unsigned;
+    #[doc(hidden)]
+    type _PowOf<U: Unsigned>: Unsigned;
+    #[doc(hidden)]
+    type Log2: Unsigned;
+    #[doc(hidden)]
+    type _Log2: Unsigned;
+    #[doc(hidden)]
+    type Sqrt: Unsigned;
+    #[doc(hidden)]
+    type Gcd<Rhs: Unsigned>: Unsigned;
+
+    // Raw operations on the bits which can leave leading zero bits.
+    // The public ones remove them so that each number has a single representation.
+    #[doc(hidden)]
+    type _AddWithCarry<Rhs: Unsigned, C: Bit>: Unsigned;
+    #[doc(hidden)]
+    type _SubWithBorrow<Rhs: Unsigned, B: Bit>: Unsigned;
+    #[doc(hidden)]
+    type _And<Rhs: Unsigned>: Unsigned;
+    #[doc(hidden)]
+    type _Or<Rhs: Unsigned>: Unsigned;
+    #[doc(hidden)]
+    type _Xor<Rhs: Unsigned>: Unsigned;
 }
 
 /// The most significant bits of `Self`.
@@ -63,10 +124,152 @@ pub type Sub<Lhs, Rhs> = <Lhs as Unsigned>::Sub<Rhs>;
 pub type SubWithBorrow<Lhs, Rhs, B> = <Lhs as Unsigned>::SubWithBorrow<Rhs, B>;
 /// Check if the Unsigned is zero.
 pub type IsZero<U> = <U as Unsigned>::IsZero;
-/// Remove the extra zero bits that can occur durring computation.
+/// Remove the leading zero bits of `U`. The result is the canonical representation of the number:
+/// its most significant bit is `B1` unless it is zero which is `Last<B0>`. All the operations
+/// return numbers in their canonical representation. A number with an [Invalid] in its bits is
+/// the [Invalid] itself.
 pub type RmExtraBits<U> = <U as Unsigned>::RmExtraBits;
 /// Result of multiplying `Self` with `Rhs`.
 pub type Mul<Lhs, Rhs> = <Lhs as Unsigned>::Mul<Rhs>;
+/// The borrow bit out of substracting `Self` with `Rhs` and a borrow bit. It is set when `Lhs < Rhs + B`.
+pub type SubBorrow<Lhs, Rhs, B> = <Lhs as Unsigned>::SubBorrow<Rhs, B>;
+/// Result of the euclidean division of `Lhs` by `Rhs`. Dividing by zero is [Invalid].
+pub type Div<Lhs, Rhs> = <Lhs as Unsigned>::Div<Rhs>;
+/// Remainder of the euclidean division of `Lhs` by `Rhs`. Dividing by zero is [Invalid].
+pub type Rem<Lhs, Rhs> = <Lhs as Unsigned>::Rem<Rhs>;
+/// The [trait@Ordering] of `Lhs` compared to `Rhs`.
+pub type Cmp<Lhs, Rhs> = <Lhs as Unsigned>::Cmp<Rhs>;
+/// [struct@bool::True] if `Lhs < Rhs`.
+pub type Lt<Lhs, Rhs> = cmp::IsLess<Cmp<Lhs, Rhs>>;
+/// [struct@bool::True] if `Lhs <= Rhs`.
+pub type Le<Lhs, Rhs> = bool::Not<cmp::IsGreater<Cmp<Lhs, Rhs>>>;
+/// [struct@bool::True] if `Lhs > Rhs`.
+pub type Gt<Lhs, Rhs> = cmp::IsGreater<Cmp<Lhs, Rhs>>;
+/// [struct@bool::True] if `Lhs >= Rhs`.
+pub type Ge<Lhs, Rhs> = bool::Not<cmp::IsLess<Cmp<Lhs, Rhs>>>;
+/// [struct@bool::True] if `Lhs == Rhs`.
+pub type Eq<Lhs, Rhs> = cmp::IsEqual<Cmp<Lhs, Rhs>>;
+/// [struct@bool::True] if `Lhs != Rhs`.
+pub type Ne<Lhs, Rhs> = bool::Not<cmp::IsEqual<Cmp<Lhs, Rhs>>>;
+/// Bitwise negation of `U` on `Width` bits: the bits above `Width` are dropped.
+pub type Not<U, Width> = Truncate<Xor<U, Mask<Width>>, Width>;
+/// Bitwise and of `Lhs` and `Rhs`.
+pub type And<Lhs, Rhs> = <Lhs as Unsigned>::And<Rhs>;
+/// Bitwise or of `Lhs` and `Rhs`.
+pub type Or<Lhs, Rhs> = <Lhs as Unsigned>::Or<Rhs>;
+/// Bitwise xor of `Lhs` and `Rhs`.
+pub type Xor<Lhs, Rhs> = <Lhs as Unsigned>::Xor<Rhs>;
+/// The result of shifting `U` left by `N` bits.
+pub type Shl<U, N> = <U as Unsigned>::Shl<N>;
+/// The result of shifting `U` right by `N` bits. Shifting by more than the width of `U` is zero.
+pub type Shr<U, N> = <U as Unsigned>::Shr<N>;
+/// `B` raised to the power `E`. `Pow<U0, U0>` is `U1`.
+pub type Pow<B, E> = <B as Unsigned>::Pow<E>;
+/// The base 2 logarithm of `U` rounded down. `Log2<U0>` is [Invalid].
+pub type Log2<U> = <U as Unsigned>::Log2;
+/// The base 2 logarithm of `U` rounded up. `Log2Ceil<U0>` is [Invalid].
+pub type Log2Ceil<U> = If<IsPowerOfTwo<U>, /*Then*/ Log2<U>, /*Else*/ Inc<Log2<U>>>;
+/// The square root of `U` rounded down.
+pub type Sqrt<U> = <U as Unsigned>::Sqrt;
+/// [struct@bool::True] if `U` is a power of two. A power of two has a single bit set so clearing
+/// its lowest set bit with `U & (U - 1)` leaves zero.
+pub type IsPowerOfTwo<U> = bool::And<bool::Not<IsZero<U>>, IsZero<And<U, Dec<U>>>>;
+/// The greatest common divisor of `Lhs` and `Rhs`. `Gcd<U0, U0>` is `U0`.
+pub type Gcd<Lhs, Rhs> = <Lhs as Unsigned>::Gcd<Rhs>;
+/// The least common multiple of `Lhs` and `Rhs`. It is `U0` if one of them is zero.
+pub type Lcm<Lhs, Rhs> = If<
+    IsZero<Gcd<Lhs, Rhs>>,
+    /*Then*/ Last<B0>,
+    /*Else*/ Mul<Div<Lhs, Gcd<Lhs, Rhs>>, Rhs>,
+>;
+/// The smallest of `Lhs` and `Rhs`.
+pub type Min<Lhs, Rhs> =
+    If<Le<Lhs, Rhs>, /*Then*/ RmExtraBits<Lhs>, /*Else*/ RmExtraBits<Rhs>>;
+/// The biggest of `Lhs` and `Rhs`.
+pub type Max<Lhs, Rhs> =
+    If<Ge<Lhs, Rhs>, /*Then*/ RmExtraBits<Lhs>, /*Else*/ RmExtraBits<Rhs>>;
+/// `U` restricted to the interval `[Lo, Hi]`. [Invalid] if `Lo > Hi`.
+pub type Clamp<U, Lo, Hi> =
+    If<Gt<Lo, Hi>, /*Then*/ Invalid<InvalidBounds>, /*Else*/ Min<Max<U, Lo>, Hi>>;
+/// The biggest number of a [trait@list::TList] of unsigned numbers. `U0` for an empty list.
+pub type MaxOf<L> = list::FoldL<L, MaxFn, Last<B0>>;
+/// The smallest number of a non empty [trait@list::TList] of unsigned numbers.
+pub type MinOf<L> = list::FoldL<list::Tail<L>, MinFn, RmExtraBits<list::Head<L>>>;
+
+/// `Lhs - Rhs` as [struct@fallible::Some] or [struct@fallible::None] if it would underflow.
+pub type CheckedSub<Lhs, Rhs> = bool::If<
+    Lt<Lhs, Rhs>,
+    /*Then*/ fallible::None,
+    /*Else*/ fallible::Some<Sub<Lhs, Rhs>>,
+>;
+/// `Lhs - Rhs` or `U0` if it would underflow.
+pub type SaturatingSub<Lhs, Rhs> =
+    If<Lt<Lhs, Rhs>, /*Then*/ Last<B0>, /*Else*/ Sub<Lhs, Rhs>>;
+/// `Lhs + Rhs` on `Width` bits: the bits above `Width` are dropped.
+pub type WrappingAdd<Lhs, Rhs, Width> = Truncate<Add<Lhs, Rhs>, Width>;
+/// `Lhs * Rhs` on `Width` bits: the bits above `Width` are dropped.
+pub type WrappingMul<Lhs, Rhs, Width> = Truncate<Mul<Lhs, Rhs>, Width>;
+
+// The number with its `Width` least significant bits set.
+type Mask<Width> = Dec<Shl<Last<B1>, Width>>;
+// Keeps the `Width` least significant bits of `U`.
+type Truncate<U, Width> = And<U, Mask<Width>>;
+
+/// [trait@TypeFn2] giving the biggest of two unsigned numbers.
+#[derive(Default)]
+pub struct MaxFn;
+impl<A: Unsigned, B: Unsigned> TypeFn2<A, B> for MaxFn {
+    type Output = Max<A, B>;
+}
+
+/// [trait@TypeFn2] giving the smallest of two unsigned numbers.
+#[derive(Default)]
+pub struct MinFn;
+impl<A: Unsigned, B: Unsigned> TypeFn2<A, B> for MinFn {
+    type Output = Min<A, B>;
+}
+
+// One step of the long division: substract `D` from the partial remainder `R` if it fits.
+type RemStep<R, D> = If<bit::IsZero<SubBorrow<R, D, B0>>, /*Then*/ Sub<R, D>, /*Else*/ R>;
+// The quotient bit of one step of the long division.
+type QuotientBit<R, D> = bit::Not<SubBorrow<R, D, B0>>;
+// The square root of `N` knowing it is either `S` or `S + 1`.
+type SqrtStep<N, S> = If<Le<Mul<Inc<S>, Inc<S>>, N>, /*Then*/ Inc<S>, /*Else*/ S>;
+
+// The binary gcd: while both numbers are non zero, halve the even ones, keeping a factor 2 if
+// both are, or replace the biggest odd one by the difference. `If` computes both of its branches
+// so the next step is done on an `Invalid` once a number is zero to stop the recursion there.
+type BinaryGcd<A, B> = If<
+    IsZero<A>,
+    /*Then*/ RmExtraBits<B>,
+    /*Else*/
+    If<
+        IsZero<B>,
+        /*Then*/ RmExtraBits<A>,
+        /*Else*/
+        If<
+            bool::And<IsEven<A>, IsEven<B>>,
+            /*Then*/ Bsl<Gcd<GcdLhs<A, B>, GcdRhs<A, B>>>,
+            /*Else*/ Gcd<GcdLhs<A, B>, GcdRhs<A, B>>,
+        >,
+    >,
+>;
+type GcdLhs<A, B> = If<
+    bool::Or<IsZero<A>, IsZero<B>>,
+    /*Then*/ Invalid,
+    /*Else*/
+    If<
+        IsEven<A>,
+        /*Then*/ Bsr<A>,
+        /*Else*/ If<IsEven<B>, /*Then*/ A, /*Else*/ Sub<Max<A, B>, Min<A, B>>>,
+    >,
+>;
+type GcdRhs<A, B> = If<
+    IsEven<B>,
+    /*Then*/ Bsr<B>,
+    /*Else*/ If<IsEven<A>, /*Then*/ B, /*Else*/ Min<A, B>>,
+>;
+type IsEven<U> = bit::IsZero<Lsb<U>>;
 
 /// `Uint` is represented as a list of bits.
 #[derive(Default)]
@@ -96,42 +299,69 @@ macro_rules! uint {
     };
 }
 
-impl Unsigned for Invalid {
+impl<Tag: ErrorTag> Unsigned for Invalid<Tag> {
     #[doc(hidden)]
-    const USIZE: usize = { panic!("Invlid Unsigned Value!") };
-    type Msb = Invalid;
-    type Lsb = Invalid;
+    const USIZE: usize = { panic!("{}", Tag::REASON) };
+    type Msb = Self;
+    type Lsb = Self;
 
-    type Bsr = Invalid;
-    type Bsl = Invalid;
+    type Bsr = Self;
+    type Bsl = Self;
 
-    type Inc = Invalid;
-    type Dec = Invalid;
+    type Inc = Self;
+    type Dec = Self;
 
-    type Add<Rhs: Unsigned> = Invalid;
-    type AddWithCarry<Rhs: Unsigned, C: Bit> = Invalid;
+    type Add<Rhs: Unsigned> = Self;
+    type AddWithCarry<Rhs: Unsigned, C: Bit> = Self;
 
-    type Sub<Rhs: Unsigned> = Invalid;
-    type SubWithBorrow<Rhs: Unsigned, B: Bit> = Invalid;
+    type Sub<Rhs: Unsigned> = Self;
+    type SubWithBorrow<Rhs: Unsigned, B: Bit> = Self;
 
-    type IsZero = Invalid;
-    type RmExtraBits = Invalid;
-    type Mul<Rhs: Unsigned> = Invalid;
+    type IsZero = Self;
+    type RmExtraBits = Self;
+    type _PushLsb<B: Bit> = Self;
+    type Mul<Rhs: Unsigned> = Self;
+    type SubBorrow<Rhs: Unsigned, B: Bit> = Self;
+    type Div<Rhs: Unsigned> = Self;
+    type Rem<Rhs: Unsigned> = Self;
+    type _Div<Rhs: Unsigned> = Self;
+    type _Rem<Rhs: Unsigned> = Self;
+    type Cmp<Rhs: Unsigned> = Self;
+    type _AddWithCarry<Rhs: Unsigned, C: Bit> = Self;
+    type _SubWithBorrow<Rhs: Unsigned, B: Bit> = Self;
+    type And<Rhs: Unsigned> = Self;
+    type Or<Rhs: Unsigned> = Self;
+    type Xor<Rhs: Unsigned> = Self;
+    type _And<Rhs: Unsigned> = Self;
+    type _Or<Rhs: Unsigned> = Self;
+    type _Xor<Rhs: Unsigned> = Self;
+    type Shl<N: Unsigned> = Self;
+    type Shr<N: Unsigned> = Self;
+    type _ShlOf<U: Unsigned> = Self;
+    type Pow<E: Unsigned> = Self;
+    type _PowOf<U: Unsigned> = Self;
+    type Log2 = Self;
+    type _Log2 = Self;
+    type Sqrt = Self;
+    type Gcd<Rhs: Unsigned> = Self;
 }
 
 impl<Lsb_: Bit> Unsigned for Last<Lsb_> {
     const USIZE: usize = Lsb_::USIZE;
     type Lsb = Lsb_;
-    type Msb = Invalid; // Shouldn't be accessed.
+    // The bits above the last one are all zeros.
+    type Msb = Last<B0>;
 
     type Inc = If<bit::IsZero<Lsb_>, /*Then*/ Last<B1>, /*Else*/ UInt<Self, B0>>;
-    type Dec = If<bit::IsZero<Lsb_>, /*Then*/ Invalid, /*Else*/ Last<B0>>;
+    type Dec = If<bit::IsZero<Lsb_>, /*Then*/ Invalid<Underflow>, /*Else*/ Last<B0>>;
 
     type Bsr = Last<B0>;
     type Bsl = If<bit::IsZero<Lsb_>, /*Then*/ Last<B0>, UInt<Self, B0>>;
 
-    type Add<Rhs: Unsigned> = If<bit::IsZero<Lsb_>, /*Then*/ Rhs, /*Else*/ Inc<Rhs>>;
-    type AddWithCarry<Rhs: Unsigned, C: Bit> = UInt<
+    type Add<Rhs: Unsigned> =
+        If<bit::IsZero<Lsb_>, /*Then*/ RmExtraBits<Rhs>, /*Else*/ Inc<Rhs>>;
+    type AddWithCarry<Rhs: Unsigned, C: Bit> = RmExtraBits<Self::_AddWithCarry<Rhs, C>>;
+    type _AddWithCarry<Rhs: Unsigned, C: Bit> = UInt<
         If<
             bit::IsZero<bit::FullCarry<Lsb_, Lsb<Rhs>, C>>,
             /*Then*/ Rhs::Msb,
@@ -140,47 +370,142 @@ impl<Lsb_: Bit> Unsigned for Last<Lsb_> {
         bit::FullAdd<Lsb_, Lsb<Rhs>, C>,
     >;
 
-    type Sub<Rhs: Unsigned> = If<bit::IsZero<Lsb_>, /*Then*/ Rhs, /*Else*/ Dec<Rhs>>;
-    type SubWithBorrow<Rhs: Unsigned, B: Bit> = If<
-        bit::IsZero<Lsb_>,
-        /*Then*/ Sub<Rhs, Last<B>>,
-        /*Else*/ Sub<Dec<Rhs>, Last<B>>,
+    type Sub<Rhs: Unsigned> = SubWithBorrow<Self, Rhs, B0>;
+    type SubWithBorrow<Rhs: Unsigned, B: Bit> = Self::_SubWithBorrow<Rhs, B>;
+    type _SubWithBorrow<Rhs: Unsigned, B: Bit> = If<
+        bit::IsZero<SubBorrow<Self, Rhs, B>>,
+        /*Then*/ Last<bit::FullDiff<Lsb_, Lsb<Rhs>, B>>,
+        /*Else*/ Invalid<Underflow>,
     >;
 
     type IsZero = bit::IsZero<Lsb_>;
     type RmExtraBits = Self;
-    type Mul<Rhs: Unsigned> = If<bit::IsZero<Lsb_>, Last<B0>, Rhs>;
+    type _PushLsb<B: Bit> =
+        If<bit::IsZero<Lsb_>, /*Then*/ Last<B>, /*Else*/ UInt<Self, B>>;
+    type Mul<Rhs: Unsigned> = If<bit::IsZero<Lsb_>, Last<B0>, RmExtraBits<Rhs>>;
+    type SubBorrow<Rhs: Unsigned, B: Bit> = <IsZero<Rhs::Msb> as Bool>::Ifbit<
+        /*Then*/ bit::FullBorrow<Lsb_, Lsb<Rhs>, B>,
+        /*Else*/ B1,
+    >;
+
+    type Div<Rhs: Unsigned> =
+        If<IsZero<Rhs>, /*Then*/ Invalid<DivByZero>, /*Else*/ Self::_Div<Rhs>>;
+    type Rem<Rhs: Unsigned> =
+        If<IsZero<Rhs>, /*Then*/ Invalid<DivByZero>, /*Else*/ Self::_Rem<Rhs>>;
+    type _Div<Rhs: Unsigned> = Last<QuotientBit<Self, Rhs>>;
+    type _Rem<Rhs: Unsigned> = RemStep<Self, Rhs>;
+    type Cmp<Rhs: Unsigned> = <IsZero<Rhs::Msb> as Bool>::Iford<
+        /*Then*/ bit::Cmp<Lsb_, Lsb<Rhs>>,
+        /*Else*/ Less,
+    >;
+
+    type And<Rhs: Unsigned> = Self::_And<Rhs>;
+    type Or<Rhs: Unsigned> = RmExtraBits<Self::_Or<Rhs>>;
+    type Xor<Rhs: Unsigned> = RmExtraBits<Self::_Xor<Rhs>>;
+    type _And<Rhs: Unsigned> = Last<bit::And<Lsb_, Lsb<Rhs>>>;
+    // The bits above the last one are zeros so they are the ones of `Rhs`.
+    type _Or<Rhs: Unsigned> = UInt<Rhs::Msb, bit::Or<Lsb_, Lsb<Rhs>>>;
+    type _Xor<Rhs: Unsigned> = UInt<Rhs::Msb, bit::Xor<Lsb_, Lsb<Rhs>>>;
+
+    type Shl<N: Unsigned> = N::_ShlOf<Self>;
+    type Shr<N: Unsigned> = If<IsZero<N>, /*Then*/ Self, /*Else*/ Last<B0>>;
+    type _ShlOf<U: Unsigned> =
+        If<bit::IsZero<Lsb_>, /*Then*/ RmExtraBits<U>, /*Else*/ Bsl<U>>;
+
+    type Pow<E: Unsigned> = E::_PowOf<Self>;
+    type _PowOf<U: Unsigned> =
+        If<bit::IsZero<Lsb_>, /*Then*/ Last<B1>, /*Else*/ RmExtraBits<U>>;
+    type Log2 = Self::_Log2;
+    type _Log2 = If<bit::IsZero<Lsb_>, /*Then*/ Invalid<LogOfZero>, /*Else*/ Last<B0>>;
+    type Sqrt = Self;
+    type Gcd<Rhs: Unsigned> = BinaryGcd<Self, Rhs>;
 }
 
 impl<Msbs: Unsigned, Lsb_: Bit> Unsigned for UInt<Msbs, Lsb_> {
     const USIZE: usize = { (Msbs::USIZE << 1) | Lsb_::USIZE };
     type Msb = Msbs;
     type Lsb = Lsb_;
-    type Bsr = Msbs;
-    type Bsl = UInt<Self, B0>;
+    type Bsr = RmExtraBits<Msbs>;
+    type Bsl = RmExtraBits<UInt<Self, B0>>;
 
     type Inc =
-        If<bit::IsZero<Lsb_>, /*Then*/ UInt<Msbs, B1>, /*Else*/ UInt<Inc<Msbs>, B0>>;
+        RmExtraBits<If<bit::IsZero<Lsb_>, /*Then*/ UInt<Msbs, B1>, /*Else*/ UInt<Inc<Msbs>, B0>>>;
     type Dec =
-        If<bit::IsZero<Lsb_>, /*Then*/ UInt<Dec<Msbs>, B1>, /*Else*/ UInt<Msbs, B0>>;
+        RmExtraBits<If<bit::IsZero<Lsb_>, /*Then*/ UInt<Dec<Msbs>, B1>, /*Else*/ UInt<Msbs, B0>>>;
 
     type Add<Rhs: Unsigned> = AddWithCarry<Self, Rhs, B0>;
-    type AddWithCarry<Rhs: Unsigned, C: Bit> = UInt<
-        AddWithCarry<Msbs, Rhs::Msb, bit::FullCarry<Lsb_, Rhs::Lsb, C>>,
+    type AddWithCarry<Rhs: Unsigned, C: Bit> = RmExtraBits<Self::_AddWithCarry<Rhs, C>>;
+    type _AddWithCarry<Rhs: Unsigned, C: Bit> = UInt<
+        Msbs::_AddWithCarry<Rhs::Msb, bit::FullCarry<Lsb_, Rhs::Lsb, C>>,
         bit::FullAdd<Lsb_, Lsb<Rhs>, C>,
     >;
 
-    type Sub<Rhs: Unsigned> = RmExtraBits<SubWithBorrow<Self, Rhs, B0>>;
-    type SubWithBorrow<Rhs: Unsigned, B: Bit> = UInt<
-        SubWithBorrow<Msbs, Msb<Rhs>, bit::FullBorrow<Lsb_, Rhs::Lsb, B>>,
+    type Sub<Rhs: Unsigned> = SubWithBorrow<Self, Rhs, B0>;
+    // The final borrow tells if the whole substraction underflows.
+    type SubWithBorrow<Rhs: Unsigned, B: Bit> = If<
+        bit::IsZero<SubBorrow<Self, Rhs, B>>,
+        /*Then*/ RmExtraBits<Self::_SubWithBorrow<Rhs, B>>,
+        /*Else*/ Invalid<Underflow>,
+    >;
+    type _SubWithBorrow<Rhs: Unsigned, B: Bit> = UInt<
+        Msbs::_SubWithBorrow<Msb<Rhs>, bit::FullBorrow<Lsb_, Rhs::Lsb, B>>,
         bit::FullDiff<Lsb_, Lsb<Rhs>, B>,
     >;
 
     type IsZero = bool::And<bit::IsZero<Lsb_>, Msbs::IsZero>;
-    type RmExtraBits =
-        If<Self::IsZero, /*Then*/ Last<B0>, /*Else*/ UInt<RmExtraBits<Msbs>, Lsb_>>;
+    // The most significant bits are normalised first so that an `Invalid` in them is kept as is.
+    type RmExtraBits = <RmExtraBits<Msbs> as Unsigned>::_PushLsb<Lsb_>;
+    type _PushLsb<B: Bit> = UInt<Self, B>;
     type Mul<Rhs: Unsigned> =
         Add<If<bit::IsZero<Lsb_>, /*Then*/ Last<B0>, /*Else*/ Rhs>, Bsl<Mul<Msbs, Rhs>>>;
+    type SubBorrow<Rhs: Unsigned, B: Bit> =
+        SubBorrow<Msbs, Msb<Rhs>, bit::FullBorrow<Lsb_, Rhs::Lsb, B>>;
+
+    type Div<Rhs: Unsigned> = If<
+        IsZero<Rhs>,
+        /*Then*/ Invalid<DivByZero>,
+        /*Else*/ RmExtraBits<Self::_Div<Rhs>>,
+    >;
+    type Rem<Rhs: Unsigned> = If<
+        IsZero<Rhs>,
+        /*Then*/ Invalid<DivByZero>,
+        /*Else*/ RmExtraBits<Self::_Rem<Rhs>>,
+    >;
+    // Binary long division: the partial remainder of the most significant bits is shifted left
+    // and the current bit is brought down.
+    type _Div<Rhs: Unsigned> = UInt<Msbs::_Div<Rhs>, QuotientBit<UInt<Msbs::_Rem<Rhs>, Lsb_>, Rhs>>;
+    type _Rem<Rhs: Unsigned> = RemStep<UInt<Msbs::_Rem<Rhs>, Lsb_>, Rhs>;
+    // The most significant bits decide unless they are equal.
+    type Cmp<Rhs: Unsigned> = cmp::Then<Cmp<Msbs, Msb<Rhs>>, bit::Cmp<Lsb_, Rhs::Lsb>>;
+
+    type And<Rhs: Unsigned> = RmExtraBits<Self::_And<Rhs>>;
+    type Or<Rhs: Unsigned> = RmExtraBits<Self::_Or<Rhs>>;
+    type Xor<Rhs: Unsigned> = RmExtraBits<Self::_Xor<Rhs>>;
+    type _And<Rhs: Unsigned> = UInt<Msbs::_And<Rhs::Msb>, bit::And<Lsb_, Lsb<Rhs>>>;
+    type _Or<Rhs: Unsigned> = UInt<Msbs::_Or<Rhs::Msb>, bit::Or<Lsb_, Lsb<Rhs>>>;
+    type _Xor<Rhs: Unsigned> = UInt<Msbs::_Xor<Rhs::Msb>, bit::Xor<Lsb_, Lsb<Rhs>>>;
+
+    // The shift left is driven by the bits of the distance: `U << (2 * Msbs + Lsb)` is
+    // `((U << Lsb) << Msbs) << Msbs`.
+    type Shl<N: Unsigned> = N::_ShlOf<Self>;
+    // The shift right drops one bit of `Self` for each unit of `N`, so it stops at the width of `Self`.
+    type Shr<N: Unsigned> =
+        If<IsZero<N>, /*Then*/ RmExtraBits<Self>, /*Else*/ Msbs::Shr<Dec<N>>>;
+    type _ShlOf<U: Unsigned> =
+        Msbs::_ShlOf<Msbs::_ShlOf<If<bit::IsZero<Lsb_>, /*Then*/ U, /*Else*/ Bsl<U>>>>;
+
+    // Exponentiation by squaring: `U^(2 * Msbs + Lsb)` is `(U^Msbs)^2 * U^Lsb`.
+    type Pow<E: Unsigned> = E::_PowOf<Self>;
+    type _PowOf<U: Unsigned> = Mul<
+        Mul<Msbs::_PowOf<U>, Msbs::_PowOf<U>>,
+        If<bit::IsZero<Lsb_>, /*Then*/ Last<B1>, /*Else*/ U>,
+    >;
+    // The logarithm is the position of the most significant bit once the leading zeros are gone.
+    type Log2 = <RmExtraBits<Self> as Unsigned>::_Log2;
+    type _Log2 = Inc<Msbs::_Log2>;
+    // With `Self = 4 * Q + R`, the root is `2 * Sqrt<Q>` or one more.
+    type Sqrt = SqrtStep<Self, Bsl<Sqrt<Msb<Msbs>>>>;
+    type Gcd<Rhs: Unsigned> = BinaryGcd<Self, Rhs>;
 }
 
 pub type U0 = uint!(B0);
@@ -218,8 +543,28 @@ pub type U31 = uint!(B1, B1, B1, B1, B1);
 pub type U32 = uint!(B1, B0, B0, B0, B0, B0);
 
 #[cfg(test)]
-mod test {
+pub(crate) mod test {
     use super::*;
+    use crate::{assert_type_eq, static_assert};
+
+    // Runs `$check` on each pair of the cross product of the `$lhs` and `$rhs` lists. `$check` is
+    // either a generic function taking the pair as type parameters or a macro `$check!` taking it
+    // as arguments.
+    macro_rules! cross {
+        ($check:ident!; $($lhs:tt),* ; $rhs:tt) => {
+            $($crate::num::unsigned::test::cross!(@row [$check!]; $lhs; $rhs);)*
+        };
+        ($check:ident; $($lhs:tt),* ; $rhs:tt) => {
+            $($crate::num::unsigned::test::cross!(@row [$check]; $lhs; $rhs);)*
+        };
+        (@row [$check:ident!]; $lhs:tt; [$($rhs:tt),*]) => {
+            $($check!($lhs, $rhs);)*
+        };
+        (@row [$check:ident]; $lhs:tt; [$($rhs:tt),*]) => {
+            $($check::<$lhs, $rhs>();)*
+        };
+    }
+    pub(crate) use cross;
 
     fn test_pair<A: Unsigned, B: Unsigned>() {
         assert_eq!(A::USIZE, B::USIZE);
@@ -232,5 +577,388 @@ mod test {
         test_pair::<<U4 as Unsigned>::Mul<U3>, U12>();
         test_pair::<<U8 as Unsigned>::Bsl, U16>();
         test_pair::<<U8 as Unsigned>::Bsr, U4>();
+        // The bits above a `Last` are zeros.
+        test_pair::<Msb<U1>, U0>();
+        test_pair::<Msb<U6>, U3>();
+    }
+
+    #[test]
+    fn test_carry_and_borrow() {
+        test_pair::<Add<U3, U3>, U6>();
+        test_pair::<Add<U7, U5>, U12>();
+        test_pair::<Add<U1, U31>, U32>();
+        test_pair::<Sub<U5, U3>, U2>();
+        test_pair::<Sub<U8, U1>, U7>();
+        test_pair::<Sub<U32, U17>, U15>();
+        test_pair::<Mul<U7, U7>, Add<U32, U17>>();
+    }
+
+    fn test_div_rem_pair<A: Unsigned, B: Unsigned>() {
+        assert_eq!(Div::<A, B>::USIZE, A::USIZE / B::USIZE);
+        assert_eq!(Rem::<A, B>::USIZE, A::USIZE % B::USIZE);
+    }
+
+    #[test]
+    fn test_div_rem() {
+        cross!(
+            test_div_rem_pair;
+            U0, U1, U2, U3, U4, U5, U7, U8, U9, U12, U15, U16, U17, U23, U31, U32;
+            [U1, U2, U3, U4, U5, U6, U7, U8, U10, U13, U16, U31, U32]
+        );
+        test_div_rem_pair::<Mul<U32, U32>, U3>();
+        test_div_rem_pair::<Mul<U31, U17>, U12>();
+        test_div_rem_pair::<Mul<U30, U30>, U30>();
+    }
+
+    fn test_cmp_pair<A: Unsigned, B: Unsigned>() {
+        assert_eq!(Cmp::<A, B>::ORDERING, A::USIZE.cmp(&B::USIZE));
+        assert_eq!(Lt::<A, B>::BOOL, A::USIZE < B::USIZE);
+        assert_eq!(Le::<A, B>::BOOL, A::USIZE <= B::USIZE);
+        assert_eq!(Gt::<A, B>::BOOL, A::USIZE > B::USIZE);
+        assert_eq!(Ge::<A, B>::BOOL, A::USIZE >= B::USIZE);
+        assert_eq!(Eq::<A, B>::BOOL, A::USIZE == B::USIZE);
+        assert_eq!(Ne::<A, B>::BOOL, A::USIZE != B::USIZE);
+    }
+
+    #[test]
+    fn test_cmp() {
+        cross!(
+            test_cmp_pair;
+            U0, U1, U2, U3, U4, U7, U8, U15, U16, U31, U32;
+            [U0, U1, U2, U3, U4, U7, U8, U15, U16, U31, U32]
+        );
+        // Leading zero bits don't change the ordering.
+        test_cmp_pair::<uint!(B0, B0, B1), U1>();
+        test_cmp_pair::<uint!(B0, B0, B0), U0>();
+        test_cmp_pair::<U2, uint!(B0, B0, B0, B1)>();
+        test_cmp_pair::<uint!(B0, B1, B0), uint!(B0, B0, B0, B1, B1)>();
+    }
+
+    #[test]
+    fn test_literal_macro() {
+        fn same_type<A: 'static, B: 'static>() -> bool {
+            std::any::TypeId::of::<A>() == std::any::TypeId::of::<B>()
+        }
+        assert!(same_type::<crate::U![0], U0>());
+        assert!(same_type::<crate::U![1], U1>());
+        assert!(same_type::<crate::U![13], U13>());
+        assert!(same_type::<crate::U![0x20], U32>());
+        assert!(same_type::<crate::U![0b1_1001], U25>());
+        assert!(same_type::<crate::U![0o17], U15>());
+        assert!(same_type::<crate::U![7u8], U7>());
+        assert_eq!(<crate::U![4096]>::USIZE, 4096);
+        assert_eq!(<crate::U![1_000_000]>::USIZE, 1_000_000);
+        assert_eq!(<crate::U![0xDEAD_BEEF]>::USIZE, 0xDEAD_BEEF);
+    }
+
+    #[test]
+    fn test_rm_extra_bits() {
+        assert_type_eq!(RmExtraBits<uint!(B0, B0, B1)>, U1);
+        assert_type_eq!(RmExtraBits<uint!(B0, B0, B0)>, U0);
+        assert_type_eq!(RmExtraBits<uint!(B0, B1, B0, B1)>, U5);
+        assert_type_eq!(RmExtraBits<U12>, U12);
+        assert_type_eq!(Div<U13, U3>, U4);
+    }
+
+    // The canonical type of the number `$n`.
+    macro_rules! canon {
+        ($n:expr) => {
+            crate::num::constant::UnsignedOf<crate::num::constant::Const<{ $n }>>
+        };
+    }
+
+    macro_rules! test_canonical_unary {
+        ($($n:literal),*) => {
+            $(
+                assert_type_eq!(Inc<crate::U![$n]>, canon!($n + 1));
+                assert_type_eq!(Dec<canon!($n + 1)>, crate::U![$n]);
+                assert_type_eq!(Bsl<crate::U![$n]>, canon!($n * 2));
+                assert_type_eq!(Bsr<crate::U![$n]>, canon!($n / 2));
+            )*
+        };
+    }
+
+    macro_rules! test_canonical_pair {
+        ($lhs:literal, $rhs:literal) => {
+            assert_type_eq!(Add<crate::U![$lhs], crate::U![$rhs]>, canon!($lhs + $rhs));
+            assert_type_eq!(Sub<canon!($lhs + $rhs), crate::U![$rhs]>, crate::U![$lhs]);
+            assert_type_eq!(Sub<crate::U![$lhs], canon!($lhs + $rhs + 1)>, Invalid<Underflow>);
+            assert_type_eq!(Mul<crate::U![$lhs], crate::U![$rhs]>, canon!($lhs * $rhs));
+            assert_type_eq!(Div<crate::U![$lhs], canon!($rhs + 1)>, canon!($lhs / ($rhs + 1)));
+            assert_type_eq!(Rem<crate::U![$lhs], canon!($rhs + 1)>, canon!($lhs % ($rhs + 1)));
+            assert_type_eq!(And<crate::U![$lhs], crate::U![$rhs]>, canon!($lhs & $rhs));
+            assert_type_eq!(Or<crate::U![$lhs], crate::U![$rhs]>, canon!($lhs | $rhs));
+            assert_type_eq!(Xor<crate::U![$lhs], crate::U![$rhs]>, canon!($lhs ^ $rhs));
+            assert_type_eq!(Shr<crate::U![$lhs], crate::U![$rhs]>, canon!($lhs >> $rhs));
+        };
+    }
+
+    #[test]
+    #[allow(clippy::modulo_one)]
+    fn test_canonical() {
+        test_canonical_unary!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+        cross!(
+            test_canonical_pair!;
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15;
+            [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]
+        );
+        // Non canonical operands still give canonical results.
+        assert_type_eq!(Inc<uint!(B0, B0, B1)>, U2);
+        assert_type_eq!(Dec<uint!(B0, B1, B0)>, U1);
+        assert_type_eq!(Bsl<uint!(B0, B0, B0)>, U0);
+        assert_type_eq!(Bsr<uint!(B0, B0, B1, B1)>, U1);
+        assert_type_eq!(Add<uint!(B0, B0), uint!(B0, B0, B1)>, U1);
+        assert_type_eq!(Add<U0, uint!(B0, B1, B1)>, U3);
+        assert_type_eq!(Mul<U1, uint!(B0, B1, B1)>, U3);
+        assert_type_eq!(Mul<uint!(B0, B1, B0), U0>, U0);
+        assert_type_eq!(AddWithCarry<U2, U1, B1>, U4);
+        assert_type_eq!(SubWithBorrow<U4, U2, B1>, U1);
+        // Underflows are `Invalid` whatever the width of the operands.
+        assert_type_eq!(Dec<U0>, Invalid<Underflow>);
+        assert_type_eq!(Dec<uint!(B0, B0, B0)>, Invalid<Underflow>);
+        assert_type_eq!(SubWithBorrow<U4, U4, B1>, Invalid<Underflow>);
+        assert_type_eq!(
+            RmExtraBits<UInt<UInt<Invalid<Underflow>, B1>, B0>>,
+            Invalid<Underflow>
+        );
+    }
+
+    #[test]
+    fn test_bitwise() {
+        assert_type_eq!(Not<U0, U1>, U1);
+        assert_type_eq!(Not<U0, U8>, crate::U![255]);
+        assert_type_eq!(Not<U1, U1>, U0);
+        assert_type_eq!(Not<U5, U3>, U2);
+        assert_type_eq!(Not<U5, U8>, crate::U![250]);
+        assert_type_eq!(Not<U8, U4>, U7);
+        assert_type_eq!(Not<U31, U5>, U0);
+        assert_type_eq!(Not<U5, U0>, U0);
+        // The bits above `Width` are dropped so negating twice gives back `U` truncated.
+        assert_type_eq!(Not<Not<U5, U8>, U8>, U5);
+        assert_type_eq!(Not<Not<U31, U3>, U3>, U7);
+        assert_type_eq!(And<U31, U32>, U0);
+        assert_type_eq!(Or<U1, U32>, crate::U![33]);
+        assert_type_eq!(Xor<crate::U![0b1100_1010], U15>, crate::U![0b1100_0101]);
+        assert_type_eq!(And<crate::U![0b1100_1010], crate::U![0b1010]>, U10);
+        assert_type_eq!(Xor<U25, U25>, U0);
+        assert_type_eq!(Or<uint!(B0, B0, B1), uint!(B0, B1, B0)>, U3);
+    }
+
+    macro_rules! test_shl_pair {
+        ($lhs:literal, $rhs:literal) => {
+            assert_type_eq!(Shl<crate::U![$lhs], crate::U![$rhs]>, canon!($lhs << $rhs));
+        };
+    }
+
+    #[test]
+    fn test_shifts() {
+        cross!(test_shl_pair!; 0, 1, 2, 3, 5, 8, 13, 15; [0, 1, 2, 3, 4]);
+        assert_type_eq!(Shl<U1, U10>, crate::U![1024]);
+        assert_type_eq!(Shl<U0, U31>, U0);
+        assert_type_eq!(Shl<U5, U32>, crate::U![0x5_0000_0000]);
+        assert_type_eq!(Shl<uint!(B0, B0, B1), U3>, U8);
+        assert_type_eq!(Shr<crate::U![0xFF00], U8>, crate::U![0xFF]);
+        assert_type_eq!(Shr<uint!(B0, B1, B1), U0>, U3);
+        // Shifting right past the width of the number.
+        assert_type_eq!(Shr<U5, U3>, U0);
+        assert_type_eq!(Shr<U5, crate::U![100]>, U0);
+        assert_type_eq!(Shr<crate::U![1_000_000], crate::U![1_000_000]>, U0);
+        assert_type_eq!(Shr<U0, U32>, U0);
+    }
+
+    macro_rules! test_pow_pair {
+        ($lhs:literal, $rhs:literal) => {
+            assert_type_eq!(
+                Pow<crate::U![$lhs], crate::U![$rhs]>,
+                canon!(($lhs as usize).pow($rhs))
+            );
+        };
+    }
+
+    macro_rules! test_log_sqrt {
+        ($($n:literal),*) => {
+            $(
+                assert_type_eq!(Log2<crate::U![$n]>, canon!(($n as usize).ilog2() as usize));
+                assert_type_eq!(
+                    Log2Ceil<crate::U![$n]>,
+                    canon!(($n as usize).next_power_of_two().ilog2() as usize)
+                );
+                assert_type_eq!(Sqrt<crate::U![$n]>, canon!(($n as usize).isqrt()));
+                assert_eq!(IsPowerOfTwo::<crate::U![$n]>::BOOL, ($n as usize).is_power_of_two());
+            )*
+        };
+    }
+
+    #[test]
+    fn test_pow_log_sqrt() {
+        cross!(test_pow_pair!; 0, 1, 2, 3, 4, 5, 6; [0, 1, 2, 3]);
+        test_log_sqrt!(
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 24, 25, 31, 32, 33, 35, 36,
+            37, 48, 63, 64, 65, 80, 81, 99, 100, 101, 127, 128, 129, 143, 144, 168, 169, 224, 225,
+            255, 256
+        );
+        assert_type_eq!(Pow<U2, U10>, crate::U![1024]);
+        assert_type_eq!(Pow<U3, U20>, crate::U![3_486_784_401]);
+        assert_type_eq!(Pow<U0, U5>, U0);
+        assert_type_eq!(Log2<U0>, Invalid<LogOfZero>);
+        assert_type_eq!(Log2Ceil<U0>, Invalid<LogOfZero>);
+        assert_type_eq!(Log2<uint!(B0, B0, B1, B0)>, U1);
+        assert_type_eq!(Log2<crate::U![1_000_000]>, U19);
+        assert_type_eq!(Log2Ceil<crate::U![1_000_000]>, U20);
+        assert_type_eq!(Sqrt<U0>, U0);
+        assert_type_eq!(Sqrt<crate::U![1_000_000]>, crate::U![1_000]);
+        assert_type_eq!(Sqrt<crate::U![999_999]>, crate::U![999]);
+        static_assert!(bool::Not<IsPowerOfTwo<U0>>);
+        static_assert!(IsPowerOfTwo<uint!(B0, B1, B0, B0)>);
+    }
+
+    macro_rules! test_gcd_pair {
+        ($lhs:literal, $rhs:literal) => {
+            assert_type_eq!(Gcd<crate::U![$lhs], crate::U![$rhs]>, canon!(gcd($lhs, $rhs)));
+            assert_type_eq!(Lcm<crate::U![$lhs], crate::U![$rhs]>, canon!(lcm($lhs, $rhs)));
+            assert_type_eq!(Min<crate::U![$lhs], crate::U![$rhs]>, canon!(min($lhs, $rhs)));
+            assert_type_eq!(Max<crate::U![$lhs], crate::U![$rhs]>, canon!(max($lhs, $rhs)));
+        };
+    }
+
+    const fn gcd(a: usize, b: usize) -> usize {
+        if b == 0 {
+            a
+        } else {
+            gcd(b, a % b)
+        }
+    }
+
+    const fn lcm(a: usize, b: usize) -> usize {
+        if a == 0 || b == 0 {
+            0
+        } else {
+            a / gcd(a, b) * b
+        }
+    }
+
+    const fn min(a: usize, b: usize) -> usize {
+        if a < b {
+            a
+        } else {
+            b
+        }
+    }
+
+    const fn max(a: usize, b: usize) -> usize {
+        if a > b {
+            a
+        } else {
+            b
+        }
+    }
+
+    #[test]
+    fn test_gcd_lcm_min_max() {
+        cross!(
+            test_gcd_pair!;
+            0, 1, 2, 3, 4, 6, 8, 9, 12, 15;
+            [0, 1, 2, 3, 4, 5, 6, 8, 9, 10, 12, 14, 15, 16]
+        );
+        assert_type_eq!(Gcd<crate::U![1_071], crate::U![462]>, U21);
+        assert_type_eq!(Gcd<crate::U![0x1_0000], crate::U![0x30_0000]>, crate::U![0x1_0000]);
+        assert_type_eq!(Lcm<U8, crate::U![4096]>, crate::U![4096]);
+        assert_type_eq!(Lcm<crate::U![1_000], crate::U![1_024]>, crate::U![128_000]);
+        assert_type_eq!(Gcd<uint!(B0, B1, B1, B0), U4>, U2);
+        assert_type_eq!(Min<uint!(B0, B0, B1), U4>, U1);
+        assert_type_eq!(Max<uint!(B0, B0, B1), U0>, U1);
+        assert_type_eq!(Gcd<U5, Invalid>, Invalid);
+        assert_type_eq!(Gcd<Invalid<Underflow>, U5>, Invalid<Underflow>);
+    }
+
+    #[test]
+    fn test_gcd_lcm_generic() {
+        fn gcd_lcm<A: Unsigned, B: Unsigned>() -> (usize, usize) {
+            (Gcd::<A, B>::USIZE, Lcm::<A, B>::USIZE)
+        }
+        assert_eq!(gcd_lcm::<U4, U6>(), (2, 12));
+        assert_eq!(gcd_lcm::<U0, U9>(), (9, 0));
+        assert_eq!(gcd_lcm::<crate::U![1_071], crate::U![462]>(), (21, 23_562));
+    }
+
+    #[test]
+    fn test_clamp() {
+        assert_type_eq!(Clamp<U1, U4, U8>, U4);
+        assert_type_eq!(Clamp<U6, U4, U8>, U6);
+        assert_type_eq!(Clamp<U9, U4, U8>, U8);
+        assert_type_eq!(Clamp<U4, U4, U4>, U4);
+        assert_type_eq!(Clamp<U4, U8, U4>, Invalid<InvalidBounds>);
+    }
+
+    #[test]
+    fn test_max_min_of() {
+        use crate::list::{Cat, End};
+        use crate::Tlist;
+        assert_type_eq!(MaxOf<Tlist![U3, U17, U4, U9]>, U17);
+        assert_type_eq!(MinOf<Tlist![U3, U17, U4, U9]>, U3);
+        assert_type_eq!(MaxOf<Tlist![U5]>, U5);
+        assert_type_eq!(MinOf<Tlist![U5]>, U5);
+        assert_type_eq!(MaxOf<End>, U0);
+        assert_type_eq!(MinOf<Tlist![uint!(B0, B1, B0), U7]>, U2);
+        assert_type_eq!(MinOf<Tlist![uint!(B0, B1)]>, U1);
+    }
+
+    macro_rules! test_overflow_pair {
+        ($lhs:literal, $rhs:literal) => {
+            assert_type_eq!(
+                CheckedSub<canon!($lhs + $rhs), crate::U![$rhs]>,
+                fallible::Some<crate::U![$lhs]>
+            );
+            assert_type_eq!(CheckedSub<crate::U![$lhs], canon!($lhs + $rhs + 1)>, fallible::None);
+            assert_type_eq!(SaturatingSub<canon!($lhs + $rhs), crate::U![$rhs]>, crate::U![$lhs]);
+            assert_type_eq!(SaturatingSub<crate::U![$lhs], canon!($lhs + $rhs + 1)>, U0);
+            test_overflow_pair!(@width $lhs; $rhs; [0, 1, 3, 4, 5, 8]);
+        };
+        (@width $lhs:literal; $rhs:literal; [$($width:literal),*]) => {
+            $(
+                assert_type_eq!(
+                    WrappingAdd<crate::U![$lhs], crate::U![$rhs], crate::U![$width]>,
+                    canon!(($lhs + $rhs) % (1 << $width))
+                );
+                assert_type_eq!(
+                    WrappingMul<crate::U![$lhs], crate::U![$rhs], crate::U![$width]>,
+                    canon!(($lhs * $rhs) % (1 << $width))
+                );
+            )*
+        };
+    }
+
+    #[test]
+    #[allow(clippy::modulo_one)]
+    fn test_overflow() {
+        cross!(test_overflow_pair!; 0, 1, 2, 3, 7, 8, 12, 15; [0, 1, 2, 5, 8, 9, 15]);
+        assert_type_eq!(CheckedSub<U0, U1>, fallible::None);
+        assert_type_eq!(fallible::UnwrapOr<CheckedSub<U3, U5>, U0>, U0);
+        assert_type_eq!(WrappingAdd<crate::U![255], U1, U8>, U0);
+        assert_type_eq!(WrappingMul<crate::U![0x1234], crate::U![0x100], U16>, crate::U![0x3400]);
+        assert_type_eq!(WrappingAdd<crate::U![255], U1, U32>, crate::U![256]);
+    }
+
+    #[test]
+    fn test_underflow() {
+        assert_type_eq!(Sub<U2, U3>, Invalid<Underflow>);
+        assert_type_eq!(Sub<U8, U9>, Invalid<Underflow>);
+        assert_type_eq!(Sub<U16, U32>, Invalid<Underflow>);
+        assert_type_eq!(Sub<U0, crate::U![1_000]>, Invalid<Underflow>);
+        assert_type_eq!(SubWithBorrow<U8, U8, B1>, Invalid<Underflow>);
+        assert_type_eq!(Dec<U0>, Invalid<Underflow>);
+        assert_type_eq!(IsZero<Sub<U8, U9>>, Invalid<Underflow>);
+        assert_type_eq!(CheckedSub<U16, U17>, fallible::None);
+        assert_type_eq!(SaturatingSub<U16, U17>, U0);
+    }
+
+    #[test]
+    fn test_div_by_zero() {
+        fn is_invalid<U: Unsigned + 'static>() -> bool {
+            std::any::TypeId::of::<U>() == std::any::TypeId::of::<Invalid<DivByZero>>()
+        }
+        assert!(is_invalid::<Div<U5, U0>>());
+        assert!(is_invalid::<Rem<U5, U0>>());
+        assert!(is_invalid::<Div<U0, U0>>());
     }
 }
@@ -0,0 +1,154 @@
+//! Serialization of [trait@TList] values as sequences (like tuples).
+use std::{fmt, marker};
+
+use ::serde::{
+    de::{self, Deserialize, Deserializer, IgnoredAny, SeqAccess, Visitor},
+    ser::{Serialize, SerializeTuple, Serializer},
+};
+
+use crate::list::{Cat, End, TList};
+
+// Serialize the elements of the list one after the other.
+trait SerializeElems {
+    fn serialize_elems<S: SerializeTuple>(&self, tuple: &mut S) -> Result<(), S::Error>;
+}
+
+impl SerializeElems for End {
+    fn serialize_elems<S: SerializeTuple>(&self, _tuple: &mut S) -> Result<(), S::Error> {
+        Ok(())
+    }
+}
+
+impl<H: Serialize, T: TList + SerializeElems> SerializeElems for Cat<H, T> {
+    fn serialize_elems<S: SerializeTuple>(&self, tuple: &mut S) -> Result<(), S::Error> {
+        tuple.serialize_element(&self.head)?;
+        self.tail.serialize_elems(tuple)
+    }
+}
+
+impl Serialize for End {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_tuple(0)?.end()
+    }
+}
+
+impl<H: Serialize, T: TList + SerializeElems> Serialize for Cat<H, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut tuple = serializer.serialize_tuple(Self::LEN)?;
+        self.serialize_elems(&mut tuple)?;
+        tuple.end()
+    }
+}
+
+// Deserialize the elements of the list one after the other. `index` is the index of the first
+// element of `Self` in the whole list of length `len`.
+trait DeserializeElems<'de>: Sized {
+    fn deserialize_elems<A: SeqAccess<'de>>(
+        seq: &mut A,
+        index: usize,
+        len: usize,
+    ) -> Result<Self, A::Error>;
+}
+
+impl<'de> DeserializeElems<'de> for End {
+    fn deserialize_elems<A: SeqAccess<'de>>(
+        _seq: &mut A,
+        _index: usize,
+        _len: usize,
+    ) -> Result<Self, A::Error> {
+        Ok(End)
+    }
+}
+
+impl<'de, H: Deserialize<'de>, T: TList + DeserializeElems<'de>> DeserializeElems<'de>
+    for Cat<H, T>
+{
+    fn deserialize_elems<A: SeqAccess<'de>>(
+        seq: &mut A,
+        index: usize,
+        len: usize,
+    ) -> Result<Self, A::Error> {
+        let head = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(index, &ExpectedLen(len)))?;
+        let tail = T::deserialize_elems(seq, index + 1, len)?;
+        Ok(Cat { head, tail })
+    }
+}
+
+struct ExpectedLen(usize);
+
+impl de::Expected for ExpectedLen {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a list of {} elements", self.0)
+    }
+}
+
+struct ListVisitor<L>(marker::PhantomData<L>);
+
+impl<'de, L: TList + DeserializeElems<'de>> Visitor<'de> for ListVisitor<L> {
+    type Value = L;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        de::Expected::fmt(&ExpectedLen(L::LEN), f)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<L, A::Error> {
+        let list = L::deserialize_elems(&mut seq, 0, L::LEN)?;
+        // Reject the sequences that are longer than the list.
+        let mut extra = 0;
+        while seq.next_element::<IgnoredAny>()?.is_some() {
+            extra += 1;
+        }
+        if extra > 0 {
+            return Err(de::Error::invalid_length(
+                L::LEN + extra,
+                &ExpectedLen(L::LEN),
+            ));
+        }
+        Ok(list)
+    }
+}
+
+impl<'de> Deserialize<'de> for End {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_tuple(0, ListVisitor(marker::PhantomData))
+    }
+}
+
+impl<'de, H: Deserialize<'de>, T: TList + DeserializeElems<'de>> Deserialize<'de> for Cat<H, T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_tuple(Self::LEN, ListVisitor(marker::PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{list::*, tlist, Tlist};
+
+    #[test]
+    fn test_round_trip() {
+        let list = tlist![1i32, String::from("Foo"), tlist!['c', 2.5f64], End];
+        let json = serde_json::to_string(&list).unwrap();
+        assert_eq!(json, r#"[1,"Foo",["c",2.5],[]]"#);
+        let back: Tlist![i32, String, Tlist![char, f64], End] =
+            serde_json::from_str(&json).unwrap();
+        assert_eq!(back, list);
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(serde_json::to_string(&End).unwrap(), "[]");
+        assert_eq!(serde_json::from_str::<End>("[]").unwrap(), End);
+        assert!(serde_json::from_str::<End>("[1]").is_err());
+    }
+
+    #[test]
+    fn test_length_mismatch() {
+        let short = serde_json::from_str::<Tlist![i32, i32, i32]>("[1, 2]").unwrap_err();
+        assert!(short.to_string().contains("invalid length 2"));
+        let long = serde_json::from_str::<Tlist![i32, i32]>("[1, 2, 3]").unwrap_err();
+        assert!(long.to_string().contains("invalid length 3"));
+        assert!(serde_json::from_str::<Tlist![i32, String]>(r#"[1, 2]"#).is_err());
+    }
+}
@@ -0,0 +1,121 @@
+//! Procedural macros of the [typers](https://github.com/Aleod-m/typers) crate. They are
+//! re-exported by `typers` and should be used from there.
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+
+/// Expand an integer literal to its `Unsigned` type.
+/// ```ignore
+/// type Page = U![4096];
+/// type Mask = U![0xFF];
+/// type Flags = U![0b1010];
+/// ```
+#[proc_macro]
+#[allow(non_snake_case)]
+pub fn U(input: TokenStream) -> TokenStream {
+    let (value, span) = match parse_literal(input) {
+        Ok(lit) => lit,
+        Err((msg, span)) => return compile_error(&msg, span),
+    };
+    unsigned_type(value, span)
+}
+
+// Parse the single integer literal given to the macro.
+fn parse_literal(input: TokenStream) -> Result<(u128, Span), (String, Span)> {
+    let mut tokens = input.into_iter();
+    let lit = match (tokens.next(), tokens.next()) {
+        (Some(TokenTree::Literal(lit)), None) => lit,
+        // Literals forwarded by a `macro_rules` macro are wrapped in an invisible group.
+        (Some(TokenTree::Group(group)), None) if group.delimiter() == Delimiter::None => {
+            return parse_literal(group.stream())
+        }
+        (Some(token), _) => {
+            return Err(("expected a single integer literal".into(), token.span()));
+        }
+        (None, _) => {
+            return Err(("expected an integer literal".into(), Span::call_site()));
+        }
+    };
+    let span = lit.span();
+    let repr = lit.to_string().replace('_', "");
+    let (radix, digits) = match repr.get(..2) {
+        Some("0x") => (16, &repr[2..]),
+        Some("0o") => (8, &repr[2..]),
+        Some("0b") => (2, &repr[2..]),
+        _ => (10, &repr[..]),
+    };
+    let digits = strip_suffix(digits);
+    if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+        return Err((format!("`{}` is not an integer literal", lit), span));
+    }
+    u128::from_str_radix(digits, radix)
+        .map(|value| (value, span))
+        .map_err(|_| (format!("`{}` does not fit in a u128", lit), span))
+}
+
+// Remove the integer type suffix of a literal (e.g. `u32`).
+fn strip_suffix(digits: &str) -> &str {
+    const SUFFIXES: [&str; 12] = [
+        "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+    ];
+    SUFFIXES
+        .iter()
+        .find_map(|suffix| digits.strip_suffix(suffix))
+        .unwrap_or(digits)
+}
+
+// Build `UInt<...UInt<Last<B1>, B_>..., B_>` from the bits of `value`.
+fn unsigned_type(value: u128, span: Span) -> TokenStream {
+    let bits = (u128::BITS - value.leading_zeros()).max(1);
+    let mut ty = generic(
+        span,
+        &["num", "unsigned", "Last"],
+        bit(value, bits - 1, span),
+    );
+    for i in (0..bits - 1).rev() {
+        let mut args = ty;
+        args.extend([TokenTree::Punct(Punct::new(',', Spacing::Alone))]);
+        args.extend(bit(value, i, span));
+        ty = generic(span, &["num", "unsigned", "UInt"], args);
+    }
+    ty
+}
+
+fn bit(value: u128, i: u32, span: Span) -> TokenStream {
+    let name = if (value >> i) & 1 == 1 { "B1" } else { "B0" };
+    path(span, &["num", "bit", name])
+}
+
+// `::typers::<segments>`.
+fn path(span: Span, segments: &[&str]) -> TokenStream {
+    let mut ts = TokenStream::new();
+    for segment in ["typers"].iter().chain(segments) {
+        ts.extend([
+            TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+            TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+            TokenTree::Ident(Ident::new(segment, span)),
+        ]);
+    }
+    ts
+}
+
+// `::typers::<segments><args>`.
+fn generic(span: Span, segments: &[&str], args: TokenStream) -> TokenStream {
+    let mut ts = path(span, segments);
+    ts.extend([TokenTree::Punct(Punct::new('<', Spacing::Alone))]);
+    ts.extend(args);
+    ts.extend([TokenTree::Punct(Punct::new('>', Spacing::Alone))]);
+    ts
+}
+
+fn compile_error(msg: &str, span: Span) -> TokenStream {
+    let mut msg = Literal::string(msg);
+    msg.set_span(span);
+    let mut group = Group::new(Delimiter::Parenthesis, TokenTree::Literal(msg).into());
+    group.set_span(span);
+    let mut bang = Punct::new('!', Spacing::Alone);
+    bang.set_span(span);
+    TokenStream::from_iter([
+        TokenTree::Ident(Ident::new("compile_error", span)),
+        TokenTree::Punct(bang),
+        TokenTree::Group(group),
+    ])
+}